@@ -0,0 +1,33 @@
+// Represents a primitive combinator, with its reduction rule
+// - S: S f g x → f x (g x)
+// - K: K x y → x
+// - I: I x → x
+// - B: B f g x → f (g x)
+// - C: C f g x → f x g
+// - W: W f x → f x x
+export type Prim = "S" | "K" | "I" | "B" | "C" | "W";
+
+// Represents a combinator expression
+// - Prim: a primitive combinator
+// - App: the application of a combinator expression to another
+// - Var: a variable, by de Bruijn index; after compilation only free variables
+//   are left, by their index at the top level
+// - Ref: a reference to an unknown definition
+export type Comb
+  = { $: "Prim", prim: Prim }
+  | { $: "App", func: Comb, arg: Comb }
+  | { $: "Var", index: number }
+  | { $: "Ref", name: string };
+
+// Represents the set of combinators bracket abstraction may use
+// - SKI: S, K and I only
+// - BCKW: Turner's optimizations, which also use B, C and W and eta-contract
+//   λx (M x) into M, giving much smaller expressions
+export type Basis = "SKI" | "BCKW";
+
+// Represents how the reduction of a combinator expression ended
+// - Normal: no combinator has enough arguments left to reduce
+// - OutOfFuel: the reduction was stopped after using up its steps
+export type CombReduction
+  = { $: "Normal", comb: Comb, steps: number }
+  | { $: "OutOfFuel", comb: Comb, steps: number };
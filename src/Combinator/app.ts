@@ -0,0 +1,9 @@
+import { Comb } from "./_";
+
+// Builds an application of combinator expressions
+// - func: the function
+// - arg: the argument
+// = the expression
+export function app(func: Comb, arg: Comb): Comb {
+  return { $: "App", func, arg };
+}
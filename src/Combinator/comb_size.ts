@@ -0,0 +1,8 @@
+import { Comb } from "./_";
+
+// Measures a combinator expression
+// - comb: the expression
+// = its number of nodes, applications included
+export function comb_size(comb: Comb): number {
+  return comb.$ === "App" ? 1 + comb_size(comb.func) + comb_size(comb.arg) : 1;
+}
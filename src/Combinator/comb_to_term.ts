@@ -0,0 +1,56 @@
+import { Term } from "../Term/_";
+import { Comb, Prim } from "./_";
+
+// The lambda term each combinator stands for
+const DEFINITIONS: { [prim in Prim]: Term } = {
+  S: lam(lam(lam(app(app(v(2), v(0)), app(v(1), v(0)))))),
+  K: lam(lam(v(1))),
+  I: lam(v(0)),
+  B: lam(lam(lam(app(v(2), app(v(1), v(0)))))),
+  C: lam(lam(lam(app(app(v(2), v(0)), v(1))))),
+  W: lam(lam(app(app(v(1), v(0)), v(0)))),
+};
+
+// Translates a combinator expression back into a lambda term
+// Each combinator is replaced by its definition, so the result is equal to the
+// compiled term up to beta and, with Turner's optimizations, eta reduction
+// - comb: the expression to translate
+// = the corresponding Term
+export function comb_to_term(comb: Comb): Term {
+  switch (comb.$) {
+    case "Prim": {
+      return DEFINITIONS[comb.prim];
+    }
+    case "App": {
+      return app(comb_to_term(comb.func), comb_to_term(comb.arg));
+    }
+    case "Var": {
+      return v(comb.index);
+    }
+    case "Ref": {
+      return { $: "Ref", name: comb.name };
+    }
+  }
+}
+
+// Builds a lambda
+// - body: the body
+// = the lambda
+function lam(body: Term): Term {
+  return { $: "Lam", body };
+}
+
+// Builds an application
+// - func: the function
+// - arg: the argument
+// = the application
+function app(func: Term, arg: Term): Term {
+  return { $: "App", func, arg };
+}
+
+// Builds a variable
+// - index: its de Bruijn index
+// = the variable
+function v(index: number): Term {
+  return { $: "Var", index };
+}
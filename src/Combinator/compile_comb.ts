@@ -0,0 +1,99 @@
+import { Term } from "../Term/_";
+import { Basis, Comb, Prim } from "./_";
+import { app } from "./app";
+
+// Compiles a term into a combinator expression by bracket abstraction
+// Lambdas are removed from the innermost out: each one is replaced by an
+// expression that, applied to an argument, rebuilds its body with the argument
+// in place of its variable. Variables free in the term are kept as such.
+// - term: the Term to compile, whose references should have been inlined
+// - basis: the combinators to use, SKI by default
+// = an equivalent combinator expression, without lambdas
+export function compile_comb(term: Term, basis: Basis = "SKI"): Comb {
+  switch (term.$) {
+    case "Var": {
+      return { $: "Var", index: term.index };
+    }
+    case "Lam": {
+      return abstract(compile_comb(term.body, basis), basis);
+    }
+    case "App": {
+      return { $: "App", func: compile_comb(term.func, basis), arg: compile_comb(term.arg, basis) };
+    }
+    case "Let": {
+      return { $: "App", func: abstract(compile_comb(term.body, basis), basis), arg: compile_comb(term.value, basis) };
+    }
+    case "Ref": {
+      return { $: "Ref", name: term.name };
+    }
+  }
+}
+
+// Abstracts the variable 0 out of a combinator expression
+// - comb: the body of a lambda, compiled
+// - basis: the combinators to use
+// = an expression E such that E x reduces to comb with x for variable 0
+function abstract(comb: Comb, basis: Basis): Comb {
+  if (!uses(comb, 0)) {
+    return app(prim("K"), lower(comb));
+  }
+  if (comb.$ !== "App") {
+    // The expression is the variable itself
+    return prim("I");
+  }
+  var { func, arg } = comb;
+  if (basis === "BCKW") {
+    if (!uses(func, 0)) {
+      return arg.$ === "Var" ? lower(func) : app(app(prim("B"), lower(func)), abstract(arg, basis));
+    }
+    if (!uses(arg, 0)) {
+      return app(app(prim("C"), abstract(func, basis)), lower(arg));
+    }
+    if (arg.$ === "Var") {
+      return app(prim("W"), abstract(func, basis));
+    }
+  }
+  return app(app(prim("S"), abstract(func, basis)), abstract(arg, basis));
+}
+
+// Checks if a combinator expression uses a variable
+// - comb: the expression to look in
+// - index: the de Bruijn index of the variable
+// = true if the variable occurs in the expression
+function uses(comb: Comb, index: number): boolean {
+  switch (comb.$) {
+    case "Var": {
+      return comb.index === index;
+    }
+    case "App": {
+      return uses(comb.func, index) || uses(comb.arg, index);
+    }
+    default: {
+      return false;
+    }
+  }
+}
+
+// Removes the binder of variable 0 from an expression that does not use it
+// - comb: the expression
+// = the expression with every variable index decremented
+function lower(comb: Comb): Comb {
+  switch (comb.$) {
+    case "Var": {
+      return { $: "Var", index: comb.index - 1 };
+    }
+    case "App": {
+      return app(lower(comb.func), lower(comb.arg));
+    }
+    default: {
+      return comb;
+    }
+  }
+}
+
+// Builds a primitive combinator
+// - name: the combinator
+// = the expression
+function prim(name: Prim): Comb {
+  return { $: "Prim", prim: name };
+}
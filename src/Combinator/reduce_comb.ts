@@ -0,0 +1,59 @@
+import { Comb, CombReduction, Prim } from "./_";
+import { app } from "./app";
+
+// The number of arguments each combinator needs to reduce
+const ARITY: { [prim in Prim]: number } = { S: 3, K: 2, I: 1, B: 3, C: 3, W: 2 };
+
+// Reduces a combinator expression to normal form, leftmost outermost first
+// No variable is ever substituted: each step only rearranges the arguments of a combinator
+// - comb: the expression to reduce
+// - fuel: the maximum number of steps, 10000 by default
+// = the normal form and the steps taken, or the expression reached when out of fuel
+export function reduce_comb(comb: Comb, fuel: number = 10000): CombReduction {
+  var counter = { steps: 0, fuel, stopped: false };
+  var reached = normalize_comb(comb, counter);
+  return { $: counter.stopped ? "OutOfFuel" : "Normal", comb: reached, steps: counter.steps };
+}
+
+// Reduces an expression to normal form, or as far as the fuel allows
+// Once the fuel is used up, the redexes left are kept as they are
+// - comb: the expression
+// - counter: the steps taken so far, the maximum, and whether a redex was left for lack of fuel
+// = the normal form, or the partially reduced expression
+function normalize_comb(comb: Comb, counter: { steps: number, fuel: number, stopped: boolean }): Comb {
+  // Unwinds the spine, and rewrites its head until it has too few arguments
+  while (true) {
+    var args: Comb[] = [];
+    var head = comb;
+    while (head.$ === "App") {
+      args.push(head.arg);
+      head = head.func;
+    }
+    args.reverse();
+    if (head.$ !== "Prim" || args.length < ARITY[head.prim]) {
+      return args.reduce<Comb>((func, arg) => app(func, normalize_comb(arg, counter)), head);
+    }
+    if (counter.steps >= counter.fuel) {
+      counter.stopped = true;
+      return comb;
+    }
+    counter.steps++;
+    var arity = ARITY[head.prim];
+    comb = args.slice(arity).reduce<Comb>(app, rewrite(head.prim, args));
+  }
+}
+
+// Applies the rule of a combinator
+// - prim: the combinator
+// - args: its arguments, at least as many as it needs
+// = the result of the rule
+function rewrite(prim: Prim, [x, y, z]: Comb[]): Comb {
+  switch (prim) {
+    case "S": return app(app(x, z), app(y, z));
+    case "K": return x;
+    case "I": return x;
+    case "B": return app(x, app(y, z));
+    case "C": return app(app(x, z), y);
+    case "W": return app(app(x, y), y);
+  }
+}
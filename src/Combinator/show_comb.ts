@@ -0,0 +1,24 @@
+import { Comb } from "./_";
+
+// Converts a combinator expression to a string, e.g. S (K S) K
+// - comb: the expression to show
+// - free: the names of the free variables, by index
+// = the string representation of the expression
+export function show_comb(comb: Comb, free: string[] = []): string {
+  switch (comb.$) {
+    case "Prim": {
+      return comb.prim;
+    }
+    case "App": {
+      var func = show_comb(comb.func, free);
+      var arg = show_comb(comb.arg, free);
+      return comb.arg.$ === "App" ? `${func} (${arg})` : `${func} ${arg}`;
+    }
+    case "Var": {
+      return free[comb.index] ?? String(comb.index);
+    }
+    case "Ref": {
+      return comb.name;
+    }
+  }
+}
@@ -0,0 +1,26 @@
+// Represents a sort, the type of types
+// - Type: the sort of propositions and data types, e.g. Π(a : Type). a → a
+// - Kind: the sort of Type itself, and of type families; it has no type
+export type Sort = "Type" | "Kind";
+
+// Represents a term of the Calculus of Constructions, where types are terms too
+// Variables are de Bruijn indices, and every binder, Π included, adds one level
+// - Sort: Type or Kind
+// - Var: a variable, by de Bruijn index
+// - Pi: the type of dependent functions Π(x : A). B, where B may use x;
+//   the arrow A → B is a Π whose body does not use its variable
+// - Lam: a function whose argument has the given type, e.g. λ(x : A) x
+// - App: the application of a term to another
+export type CTerm
+  = { $: "Sort", sort: Sort }
+  | { $: "Var", index: number }
+  | { $: "Pi", name?: string, domain: CTerm, body: CTerm }
+  | { $: "Lam", name?: string, type: CTerm, body: CTerm }
+  | { $: "App", func: CTerm, arg: CTerm };
+
+// Represents the result of type checking a term of the Calculus of Constructions
+// - Ok: the type of the term, in normal form
+// - Err: why the term is not well typed
+export type CoreCheck
+  = { $: "Ok", type: CTerm }
+  | { $: "Err", message: string };
@@ -0,0 +1,18 @@
+import { Binding } from "../Reducer/_";
+import { CTerm } from "./_";
+
+// Where terms of the Calculus of Constructions bind variables: a Π or a
+// lambda binds one in its body, but neither in its domain nor in its type
+export const CORE_BINDING: Binding<CTerm> = {
+  index: (term) => term.$ === "Var" ? term.index : null,
+  variable: (index) => ({ $: "Var", index }),
+  map: (term, child) => {
+    switch (term.$) {
+      case "Sort": return term;
+      case "Var": return term;
+      case "Pi": return { $: "Pi", name: term.name, domain: child(term.domain, 0), body: child(term.body, 1) };
+      case "Lam": return { $: "Lam", name: term.name, type: child(term.type, 0), body: child(term.body, 1) };
+      case "App": return { $: "App", func: child(term.func, 0), arg: child(term.arg, 0) };
+    }
+  },
+};
@@ -0,0 +1,121 @@
+import { CoreCheck, CTerm, Sort } from "./_";
+import { shift_core } from "./shift_core";
+import { beta_core } from "./substitute_core";
+import { convertible, normalize_core, whnf_core } from "./normalize_core";
+import { show_core } from "./show_core";
+
+// The typing context of a subterm
+// - types: the type of each enclosing binder, innermost last, valid in the
+//   context of that binder
+// - names: the names of the enclosing binders, for messages
+type Context = { types: CTerm[], names: string[] };
+
+// Thrown when a subterm is not well typed
+type CoreError = { $: "CoreError", message: string };
+
+// Type checks a closed term of the Calculus of Constructions
+// This is the kernel of a proof checker: a proposition is a type, and a proof
+// of it is a term of that type. Types are compared by normalizing them, using
+// the same shifting and substitution discipline as the untyped reducer.
+// - term: the CTerm to check
+// = the normal form of its type, or why it is not well typed
+export function check_core(term: CTerm): CoreCheck {
+  try {
+    return { $: "Ok", type: normalize_core(infer_at(term, { types: [], names: [] })) };
+  } catch (e) {
+    if (typeof e === "object" && e !== null && (e as CoreError).$ === "CoreError") {
+      return { $: "Err", message: (e as CoreError).message };
+    }
+    throw e;
+  }
+}
+
+// Computes the type of a subterm
+// - term: the subterm
+// - ctx: its typing context, extended in place and restored
+// = the type of the subterm, valid in its context
+function infer_at(term: CTerm, ctx: Context): CTerm {
+  switch (term.$) {
+    case "Sort": {
+      if (term.sort === "Kind") {
+        throw fail("Kind has no type");
+      }
+      return { $: "Sort", sort: "Kind" };
+    }
+    case "Var": {
+      // The type was valid where the variable was bound, index + 1 binders up
+      return shift_core(ctx.types[ctx.types.length - 1 - term.index], term.index + 1, 0);
+    }
+    case "Pi": {
+      const { domain, body } = term;
+      sort_of(domain, ctx);
+      var sort = with_binder(term.name, domain, ctx, () => sort_of(body, ctx));
+      // The Calculus of Constructions is impredicative: Π(a : Type). a → a is a Type
+      return { $: "Sort", sort };
+    }
+    case "Lam": {
+      const { name, type, body } = term;
+      sort_of(type, ctx);
+      var body_type = with_binder(name, type, ctx, () => infer_at(body, ctx));
+      var pi: CTerm = { $: "Pi", name, domain: type, body: body_type };
+      sort_of(pi, ctx);
+      return pi;
+    }
+    case "App": {
+      var func = whnf_core(infer_at(term.func, ctx));
+      if (func.$ !== "Pi") {
+        throw fail(`${show(term.func, ctx)} has type ${show(func, ctx)}, which is not a function type`);
+      }
+      var arg = infer_at(term.arg, ctx);
+      if (!convertible(func.domain, arg)) {
+        throw fail(`${show(term.func, ctx)} expects an argument of type ${show(normalize_core(func.domain), ctx)}, `
+          + `but ${show(term.arg, ctx)} has type ${show(normalize_core(arg), ctx)}`);
+      }
+      return beta_core(func.body, term.arg);
+    }
+  }
+}
+
+// Checks that a term is a type, i.e. that its own type is a sort
+// - term: the term
+// - ctx: its typing context
+// = the sort of the term
+function sort_of(term: CTerm, ctx: Context): Sort {
+  var type = whnf_core(infer_at(term, ctx));
+  if (type.$ !== "Sort") {
+    throw fail(`${show(term, ctx)} is not a type, its type is ${show(normalize_core(type), ctx)}`);
+  }
+  return type.sort;
+}
+
+// Runs a computation under one more binder
+// - name: the name of the binder
+// - type: the type of its variable
+// - ctx: the typing context, extended during the computation
+// - run: the computation
+// = the result of the computation
+function with_binder<T>(name: string | undefined, type: CTerm, ctx: Context, run: () => T): T {
+  ctx.types.push(type);
+  ctx.names.push(name ?? `x${ctx.names.length}`);
+  try {
+    return run();
+  } finally {
+    ctx.types.pop();
+    ctx.names.pop();
+  }
+}
+
+// Shows a subterm in its context, for messages
+// - term: the subterm
+// - ctx: its typing context
+// = the string representation of the subterm
+function show(term: CTerm, ctx: Context): string {
+  return show_core(term, ctx.names);
+}
+
+// Builds an error
+// - message: a description of the failure
+// = the error
+function fail(message: string): CoreError {
+  return { $: "CoreError", message };
+}
@@ -0,0 +1,75 @@
+import { CTerm } from "./_";
+import { beta_core } from "./substitute_core";
+
+// Computes the beta-normal form of a term, reducing under every binder
+// Well-typed terms of the Calculus of Constructions always have one
+// - term: the term to normalize
+// = its normal form
+export function normalize_core(term: CTerm): CTerm {
+  switch (term.$) {
+    case "Sort":
+    case "Var": {
+      return term;
+    }
+    case "Pi": {
+      return { $: "Pi", name: term.name, domain: normalize_core(term.domain), body: normalize_core(term.body) };
+    }
+    case "Lam": {
+      return { $: "Lam", name: term.name, type: normalize_core(term.type), body: normalize_core(term.body) };
+    }
+    case "App": {
+      var func = whnf_core(term.func);
+      if (func.$ === "Lam") {
+        return normalize_core(beta_core(func.body, term.arg));
+      }
+      return { $: "App", func: normalize_core(func), arg: normalize_core(term.arg) };
+    }
+  }
+}
+
+// Reduces a term until its head is not a beta redex
+// - term: the term to reduce
+// = its weak head normal form
+export function whnf_core(term: CTerm): CTerm {
+  while (term.$ === "App") {
+    var func = whnf_core(term.func);
+    if (func.$ !== "Lam") {
+      return { $: "App", func, arg: term.arg };
+    }
+    term = beta_core(func.body, term.arg);
+  }
+  return term;
+}
+
+// Checks if two terms are definitionally equal, i.e. have the same normal form
+// Binder names are ignored, as they do not change the indices
+// - a: a well-typed term
+// - b: another well-typed term
+// = true if the terms are convertible
+export function convertible(a: CTerm, b: CTerm): boolean {
+  return same(normalize_core(a), normalize_core(b));
+}
+
+// Checks if two terms are syntactically equal, up to binder names
+// - a: a term
+// - b: another term
+// = true if the terms are the same
+function same(a: CTerm, b: CTerm): boolean {
+  switch (a.$) {
+    case "Sort": {
+      return b.$ === "Sort" && a.sort === b.sort;
+    }
+    case "Var": {
+      return b.$ === "Var" && a.index === b.index;
+    }
+    case "Pi": {
+      return b.$ === "Pi" && same(a.domain, b.domain) && same(a.body, b.body);
+    }
+    case "Lam": {
+      return b.$ === "Lam" && same(a.type, b.type) && same(a.body, b.body);
+    }
+    case "App": {
+      return b.$ === "App" && same(a.func, b.func) && same(a.arg, b.arg);
+    }
+  }
+}
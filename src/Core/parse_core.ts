@@ -0,0 +1,136 @@
+import { CTerm } from "./_";
+import { Cursor, ParseError } from "../Parser/_";
+import { is_parse_error } from "../Parser/error";
+import { expect, fail, parse_identifier, peek_identifier, skip_whitespace } from "../Parser/cursor";
+
+// A parser for closed terms of the Calculus of Constructions
+// Binders are annotated: λ(x : A). M and Π(x : A). B, also written \ and
+// forall; several binders may follow one λ or Π, and the dot is optional.
+// A → B, or A -> B, is a Π whose body does not use its variable. The sorts
+// are written Type and Kind, so that the types check_core infers parse back.
+// - input: the string to be parsed
+// = the parsed CTerm, or a ParseError locating the failure
+export const parse_core = (input: string): { $: "Ok", term: CTerm } | { $: "Err", error: ParseError } => {
+  const cursor: Cursor = { input, index: 0 };
+  let bound_vars: string[] = [];
+
+  // Helper function to parse a term: applications, then arrows to the right
+  const parse_term = (): CTerm => {
+    skip_whitespace(cursor);
+    const domain = parse_app();
+    skip_whitespace(cursor);
+    if (input[cursor.index] === '→' || input.startsWith("->", cursor.index)) {
+      cursor.index += input[cursor.index] === '→' ? 1 : 2;
+      // The codomain is under an anonymous binder
+      bound_vars.push("");
+      const body = parse_term();
+      bound_vars.pop();
+      return { $: "Pi", domain, body };
+    }
+    return domain;
+  };
+
+  // Helper function to parse an atom applied to atoms
+  const parse_app = (): CTerm => {
+    let term = parse_atom();
+    while (true) {
+      skip_whitespace(cursor);
+      if (!starts_atom()) break;
+      const arg = parse_atom();
+      term = { $: "App", func: term, arg };
+    }
+    return term;
+  };
+
+  // Helper function to parse an atom (sort, variable, binder, or parenthesized term)
+  const parse_atom = (): CTerm => {
+    skip_whitespace(cursor);
+
+    if (cursor.index >= input.length) {
+      return fail(cursor, ATOM, "unexpected end of input");
+    }
+
+    if (input[cursor.index] === 'λ' || input[cursor.index] === '\\') {
+      cursor.index++; // Skip 'λ' or '\\'
+      return parse_binders((name, type, body) => ({ $: "Lam", name, type, body }));
+    }
+    if (input[cursor.index] === 'Π' || input[cursor.index] === '∀' || peek_identifier(cursor) === "forall") {
+      cursor.index += input[cursor.index] === 'Π' || input[cursor.index] === '∀' ? 1 : "forall".length;
+      return parse_binders((name, domain, body) => ({ $: "Pi", name, domain, body }));
+    }
+    if (input[cursor.index] === '(') {
+      cursor.index++; // Skip '('
+      const term = parse_term();
+      expect(cursor, ")");
+      return term;
+    }
+
+    const start = cursor.index;
+    const name = parse_identifier(cursor);
+    if (!name) {
+      return fail(cursor, ATOM, `unexpected '${input[cursor.index]}'`);
+    }
+    if (name === "Type" || name === "Kind") {
+      return { $: "Sort", sort: name };
+    }
+    if (name === "forall") {
+      return fail(cursor, ATOM, "unexpected keyword 'forall'", start);
+    }
+    const var_index = bound_vars.lastIndexOf(name);
+    if (var_index === -1) {
+      return fail(cursor, [], `unbound variable '${name}'`, start);
+    }
+    return { $: "Var", index: bound_vars.length - var_index - 1 };
+  };
+
+  // Helper function to parse annotated binders (x : A) (y : B) and the body they scope over
+  const parse_binders = (build: (name: string, type: CTerm, body: CTerm) => CTerm): CTerm => {
+    const binders: Array<[string, CTerm]> = [];
+    do {
+      expect(cursor, "(");
+      skip_whitespace(cursor);
+      const start = cursor.index;
+      const name = parse_identifier(cursor);
+      if (!name || name === "Type" || name === "Kind" || name === "forall") {
+        return fail(cursor, ["variable"], "expected a binder name", start);
+      }
+      expect(cursor, ":");
+      const type = parse_term();
+      expect(cursor, ")");
+      binders.push([name, type]);
+      bound_vars.push(name);
+      skip_whitespace(cursor);
+    } while (input[cursor.index] === '(');
+    if (input[cursor.index] === '.') {
+      cursor.index++; // Skip '.'
+    }
+    const body = parse_term();
+    bound_vars.splice(bound_vars.length - binders.length);
+    return binders.reduceRight((body, [name, type]) => build(name, type, body), body);
+  };
+
+  // Helper function to check if the next character can start an atom
+  const starts_atom = (): boolean => {
+    return cursor.index < input.length && /[λ\\Π∀(a-zA-Z_]/.test(input[cursor.index]);
+  };
+
+  try {
+    // Parse the entire input
+    const term = parse_term();
+    skip_whitespace(cursor);
+
+    // Ensure we've consumed all input
+    if (cursor.index < input.length) {
+      return fail(cursor, [...ATOM, "→", "end of input"], `unexpected '${input[cursor.index]}'`);
+    }
+    return { $: "Ok", term };
+  } catch (e) {
+    if (is_parse_error(e)) {
+      return { $: "Err", error: e };
+    }
+    throw e;
+  }
+};
+
+// The tokens that can start an atom
+const ATOM = ["variable", "Type", "Kind", "λ", "Π", "("];
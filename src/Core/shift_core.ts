@@ -0,0 +1,12 @@
+import { CTerm } from "./_";
+import { CORE_BINDING } from "./binding";
+import { shift_in } from "../Reducer/shift";
+
+// Shifts the de Bruijn indices in a term, with the same shift as untyped terms
+// - term: the term to shift
+// - by: the amount to shift by
+// - from: the cutoff index
+// = the shifted term
+export function shift_core(term: CTerm, by: number, from: number): CTerm {
+  return shift_in(CORE_BINDING, term, by, from);
+}
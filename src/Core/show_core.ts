@@ -0,0 +1,79 @@
+import { CTerm } from "./_";
+
+// Converts a term of the Calculus of Constructions to a string
+// A Π whose body does not use its variable is shown as an arrow, e.g.
+// Π(a : Type). (a → a) → a → a
+// - term: the term to show
+// - names: the names of the variables in scope, innermost last
+// = the string representation of the term
+export function show_core(term: CTerm, names: string[] = []): string {
+  switch (term.$) {
+    case "Sort": {
+      return term.sort;
+    }
+    case "Var": {
+      return names[names.length - 1 - term.index] ?? `?${term.index}`;
+    }
+    case "Pi": {
+      if (!uses(term.body, 0)) {
+        var domain = show_core(term.domain, names);
+        var codomain = show_core(term.body, [...names, "_"]);
+        return is_atomic(term.domain) || term.domain.$ === "App" ? `${domain} → ${codomain}` : `(${domain}) → ${codomain}`;
+      }
+      var name = binder(term.name, names);
+      return `Π(${name} : ${show_core(term.domain, names)}). ${show_core(term.body, [...names, name])}`;
+    }
+    case "Lam": {
+      var name = binder(term.name, names);
+      return `λ(${name} : ${show_core(term.type, names)}). ${show_core(term.body, [...names, name])}`;
+    }
+    case "App": {
+      var func = term.func.$ === "Lam" || term.func.$ === "Pi" ? `(${show_core(term.func, names)})` : show_core(term.func, names);
+      var arg = is_atomic(term.arg) ? show_core(term.arg, names) : `(${show_core(term.arg, names)})`;
+      return `${func} ${arg}`;
+    }
+  }
+}
+
+// Checks if a term is shown without spaces
+// - term: the term
+// = true for sorts and variables
+function is_atomic(term: CTerm): boolean {
+  return term.$ === "Sort" || term.$ === "Var";
+}
+
+// Picks the name of a binder
+// - name: the name it was written with, if any
+// - names: the names in scope
+// = the name, primed until it does not shadow another
+function binder(name: string | undefined, names: string[]): string {
+  var chosen = name ?? "x";
+  while (names.includes(chosen)) {
+    chosen += "'";
+  }
+  return chosen;
+}
+
+// Checks if a term uses a variable
+// - term: the term to look in
+// - index: the de Bruijn index of the variable
+// = true if the variable occurs in the term
+function uses(term: CTerm, index: number): boolean {
+  switch (term.$) {
+    case "Sort": {
+      return false;
+    }
+    case "Var": {
+      return term.index === index;
+    }
+    case "Pi": {
+      return uses(term.domain, index) || uses(term.body, index + 1);
+    }
+    case "Lam": {
+      return uses(term.type, index) || uses(term.body, index + 1);
+    }
+    case "App": {
+      return uses(term.func, index) || uses(term.arg, index);
+    }
+  }
+}
@@ -0,0 +1,20 @@
+import { CTerm } from "./_";
+import { CORE_BINDING } from "./binding";
+import { substitute_in } from "../Reducer/substitute";
+
+// Substitutes a term for a variable in another term, with the same substitute as untyped terms
+// - term: the term to perform substitution in
+// - index: the de Bruijn index to substitute for
+// - replacement: the term to substitute
+// = the term after substitution
+export function substitute_core(term: CTerm, index: number, replacement: CTerm): CTerm {
+  return substitute_in(CORE_BINDING, term, index, replacement);
+}
+
+// Contracts a beta redex (λ(x : A) body) arg, or instantiates a Π with an argument
+// - body: the body of the lambda or Π
+// - arg: the argument
+// = the body with the argument for its variable
+export function beta_core(body: CTerm, arg: CTerm): CTerm {
+  return substitute_core(body, 0, arg);
+}
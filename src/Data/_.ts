@@ -0,0 +1,16 @@
+import { Term } from "../Term/_";
+
+// Represents a value that a normal form encodes
+// - Nat: a natural number, as a Church numeral λf x. f (f x) or a Scott
+//   numeral, where 0 is λz s. z and n + 1 is λz s. s n
+// - Bool: a Church boolean, λt f. t or λt f. f
+// - Pair: a Church pair λs. s a b
+// - List: a Church list λc n. c x (c y n), i.e. its own right fold
+// - Term: a component of a pair or list that encodes nothing recognized,
+//   in the context of the whole term
+export type Data
+  = { $: "Nat", value: number, encoding: "Church" | "Scott" }
+  | { $: "Bool", value: boolean }
+  | { $: "Pair", first: Data, second: Data }
+  | { $: "List", items: Data[] }
+  | { $: "Term", term: Term };
@@ -0,0 +1,45 @@
+import { Term } from "../Term/_";
+import { Data } from "./_";
+import { read_church_nat, read_scott_nat } from "./read_nat";
+import { read_bool } from "./read_bool";
+import { read_pair } from "./read_pair";
+import { read_list } from "./read_list";
+
+// Lists the values that a normal form encodes
+// Encodings overlap: λf x. x is the Church numeral 0, false and the empty
+// list, and λt f. t is both true and the Scott numeral 0, so every reading
+// is returned, Church numerals first, then booleans, Scott numerals, pairs
+// and lists
+// - term: the term to decode, in normal form
+// = the readings of the term, none if it encodes nothing recognized
+export function decode(term: Term): Data[] {
+  var readings: Data[] = [];
+  var church = read_church_nat(term);
+  if (church !== null) {
+    readings.push({ $: "Nat", value: church, encoding: "Church" });
+  }
+  var bool = read_bool(term);
+  if (bool !== null) {
+    readings.push({ $: "Bool", value: bool });
+  }
+  var scott = read_scott_nat(term);
+  if (scott !== null) {
+    readings.push({ $: "Nat", value: scott, encoding: "Scott" });
+  }
+  var pair = read_pair(term);
+  if (pair !== null) {
+    readings.push({ $: "Pair", first: component(pair[0]), second: component(pair[1]) });
+  }
+  var list = read_list(term);
+  if (list !== null) {
+    readings.push({ $: "List", items: list.map(component) });
+  }
+  return readings;
+}
+
+// Decodes a component of a pair or list by its first reading
+// - term: the component
+// = the value it encodes, or the component itself
+function component(term: Term): Data {
+  return decode(term)[0] ?? { $: "Term", term };
+}
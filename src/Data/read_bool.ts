@@ -0,0 +1,12 @@
+import { Term } from "../Term/_";
+
+// Reads a Church boolean: true is λt f. t, and false is λt f. f
+// - term: the term to read, in normal form
+// = the boolean it encodes, or null if it is no boolean
+export function read_bool(term: Term): boolean | null {
+  if (term.$ !== "Lam" || term.body.$ !== "Lam" || term.body.body.$ !== "Var") {
+    return null;
+  }
+  var index = term.body.body.index;
+  return index === 1 ? true : index === 0 ? false : null;
+}
@@ -0,0 +1,24 @@
+import { Term } from "../Term/_";
+import { occurs_free } from "../Term/occurs_free";
+import { shift } from "../Reducer/shift";
+
+// Reads a Church list, λc n. c x (c y n), which folds c over its items
+// - term: the term to read, in normal form
+// = the items, in the context of the list, or null if it is no list
+export function read_list(term: Term): Term[] | null {
+  if (term.$ !== "Lam" || term.body.$ !== "Lam") {
+    return null;
+  }
+  var body = term.body.body;
+  var items: Term[] = [];
+  while (body.$ === "App" && body.func.$ === "App" && body.func.func.$ === "Var" && body.func.func.index === 1) {
+    var item = body.func.arg;
+    // The items cannot look at the fold's arguments
+    if (occurs_free(0, item) || occurs_free(1, item)) {
+      return null;
+    }
+    items.push(shift(item, -2, 0));
+    body = body.arg;
+  }
+  return body.$ === "Var" && body.index === 0 ? items : null;
+}
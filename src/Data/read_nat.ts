@@ -0,0 +1,37 @@
+import { Term } from "../Term/_";
+
+// Reads a Church numeral, λf x. f (f ... (f x))
+// - term: the term to read, in normal form
+// = the number it encodes, or null if it is no Church numeral
+export function read_church_nat(term: Term): number | null {
+  if (term.$ !== "Lam" || term.body.$ !== "Lam") {
+    return null;
+  }
+  var body = term.body.body;
+  var value = 0;
+  while (body.$ === "App" && body.func.$ === "Var" && body.func.index === 1) {
+    body = body.arg;
+    value++;
+  }
+  return body.$ === "Var" && body.index === 0 ? value : null;
+}
+
+// Reads a Scott numeral: 0 is λz s. z, and n + 1 is λz s. s n
+// - term: the term to read, in normal form
+// = the number it encodes, or null if it is no Scott numeral
+export function read_scott_nat(term: Term): number | null {
+  var value = 0;
+  while (term.$ === "Lam" && term.body.$ === "Lam") {
+    var body = term.body.body;
+    if (body.$ === "Var" && body.index === 1) {
+      return value;
+    }
+    if (body.$ !== "App" || body.func.$ !== "Var" || body.func.index !== 0) {
+      return null;
+    }
+    // The predecessor can only refer to its own binders, or it would not be a numeral
+    term = body.arg;
+    value++;
+  }
+  return null;
+}
@@ -0,0 +1,23 @@
+import { Term } from "../Term/_";
+import { occurs_free } from "../Term/occurs_free";
+import { shift } from "../Reducer/shift";
+
+// Reads a Church pair, λs. s a b
+// - term: the term to read, in normal form
+// = the two components, in the context of the pair, or null if it is no pair
+export function read_pair(term: Term): [Term, Term] | null {
+  if (term.$ !== "Lam") {
+    return null;
+  }
+  var body = term.body;
+  if (body.$ !== "App" || body.func.$ !== "App" || body.func.func.$ !== "Var" || body.func.func.index !== 0) {
+    return null;
+  }
+  var first = body.func.arg;
+  var second = body.arg;
+  // The components cannot look at the selector
+  if (occurs_free(0, first) || occurs_free(0, second)) {
+    return null;
+  }
+  return [shift(first, -1, 0), shift(second, -1, 0)];
+}
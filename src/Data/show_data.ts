@@ -0,0 +1,27 @@
+import { Data } from "./_";
+import { show_named } from "../Term/show_named";
+
+// Converts a decoded value to a string, such as 3, true, (1, false) or [1,2]
+// Scott numerals are marked, as in scott 3, to tell them from Church ones
+// - data: the value to show
+// - free: the names of the free variables of the decoded term
+// = the string representation of the value
+export function show_data(data: Data, free: string[] = []): string {
+  switch (data.$) {
+    case "Nat": {
+      return data.encoding === "Scott" ? `scott ${data.value}` : String(data.value);
+    }
+    case "Bool": {
+      return String(data.value);
+    }
+    case "Pair": {
+      return `(${show_data(data.first, free)}, ${show_data(data.second, free)})`;
+    }
+    case "List": {
+      return `[${data.items.map(item => show_data(item, free)).join(",")}]`;
+    }
+    case "Term": {
+      return show_named(data.term, { free });
+    }
+  }
+}
@@ -0,0 +1,113 @@
+import { Term } from "../Term/_";
+import { Normalization } from "../Reducer/_";
+
+// Represents an environment: a linked list whose first entry binds de Bruijn index 0
+// - first: the entry bound by the innermost binder
+// - rest: the entries of the enclosing binders
+export type Env<T> = null | { first: T, rest: Env<T> };
+
+// Represents the head of a stuck application
+// - Free: a free variable of the evaluated term, by its index at the top level
+// - Level: a variable bound by a lambda being read back, by its depth
+// - Ref: a reference to an unknown definition
+export type Head
+  = { $: "Free", index: number }
+  | { $: "Level", level: number }
+  | { $: "Ref", name: string };
+
+// Represents the result of evaluating a term with call-by-need
+// - Closure: a lambda together with the environment of its free variables
+// - Neutral: a head applied to arguments, which cannot reduce further
+export type Value
+  = { $: "Closure", name?: string, body: Term, env: Env<Thunk> }
+  | { $: "Neutral", head: Head, spine: Thunk[] };
+
+// Represents a suspended computation, evaluated at most once
+// - state: the pending term, replaced by its value once forced
+export type Thunk = { state: Suspension };
+
+// Represents the state of a thunk
+// - Delayed: a term and the environment it is to be evaluated in
+// - Forced: the value it evaluated to
+export type Suspension
+  = { $: "Delayed", term: Term, env: Env<Thunk> }
+  | { $: "Forced", value: Value };
+
+// Counts the rules applied during an evaluation
+// - beta: the applications of a lambda to an argument
+// - delta: the definitions unfolded
+// - zeta: the local definitions entered
+export type Counts = { beta: number, delta: number, zeta: number };
+
+// Represents the options of an evaluator
+// - defs: the top-level definitions, unfolded on demand
+// - fuel: the maximum number of beta steps, 10000 by default
+export type MachineOptions = { defs?: Map<string, Term>, fuel?: number };
+
+// Represents how an evaluation ended
+// - Done: the evaluator halted, with the term it computed: the normal form for
+//   call-by-need, the term its reduction strategy stops at for the other machines
+// - OutOfFuel: the evaluation was stopped after using up its beta steps
+// - StackOverflow: the evaluator recursed deeper than the host stack allows,
+//   so that more fuel would not help
+export type Evaluation
+  = { $: "Done", term: Term, counts: Counts }
+  | { $: "OutOfFuel", counts: Counts }
+  | { $: "StackOverflow", counts: Counts };
+
+// Compares call-by-need with call-by-name on the same term
+// - need: the outcome of call-by-need evaluation
+// - name: the outcome of normal order reduction, i.e. call-by-name to normal form
+// - name_beta: the number of beta steps taken by normal order reduction
+// - saved: the beta steps saved by sharing, or null if either run did not finish
+export type Sharing = { need: Evaluation, name: Normalization, name_beta: number, saved: number | null };
+
+// Represents a term paired with the environment of its free variables
+// - term: the suspended term
+// - env: the closures bound to its variables
+export type Closure = { term: Term, env: Env<Closure> };
+
+// Represents a value of the CEK machine
+// - Closure: a lambda together with the environment of its free variables
+// - Atom: a free variable or an unknown reference, which are values too
+export type CekValue
+  = { $: "Closure", name?: string, body: Term, env: Env<CekValue> }
+  | { $: "Atom", head: Exclude<Head, { $: "Level" }> };
+
+// Represents what remains to be done once the CEK machine has computed a value
+// - Arg: the value is a function, whose argument is evaluated next
+// - Fun: the value is the argument of the given function
+// - Bind: the value is bound by a let, whose body is evaluated next
+export type Frame
+  = { $: "Arg", term: Term, env: Env<CekValue> }
+  | { $: "Fun", func: Extract<CekValue, { $: "Closure" }> }
+  | { $: "Bind", name?: string, body: Term, env: Env<CekValue> };
+
+// Represents the timings of an evaluator against the substitution reducer
+// - machine: the milliseconds taken by the abstract machine
+// - reducer: the milliseconds taken by the substitution reducer
+// - steps: the beta steps taken by the machine
+// - agree: whether both computed the same term
+export type Timing = { machine: number, reducer: number, steps: number, agree: boolean };
+
+// Represents the semantic value of a term, for normalization by evaluation
+// - Fun: a lambda, as a host function from its argument to its body's value
+// - Neutral: a head applied to arguments, which cannot reduce further
+export type Sem
+  = { $: "Fun", name?: string, apply: (arg: Lazy) => Sem }
+  | { $: "Neutral", head: Head, spine: Lazy[] };
+
+// Represents a semantic value computed on first use, then remembered
+export type Lazy = () => Sem;
+
+// Represents the outcome of checking a reducer against normalization by evaluation
+// - Agree: both computed the given normal form
+// - Disagree: they computed different normal forms
+// - StackOverflow: normalization by evaluation exhausted the host stack after
+//   some beta steps; reducer is the normal form found by normal order, if any
+// - Unknown: either did not reach a normal form within its budget
+export type CrossCheck
+  = { $: "Agree", term: Term }
+  | { $: "Disagree", nbe: Term, reducer: Term }
+  | { $: "StackOverflow", beta: number, reducer: Term | null }
+  | { $: "Unknown" };
@@ -0,0 +1,44 @@
+import { Term } from "../Term/_";
+import { equal } from "../Term/equal";
+import { Evaluation, MachineOptions, Timing } from "./_";
+import { Strategy } from "../Reducer/_";
+import { normalize } from "../Reducer/normalize";
+import { krivine } from "./krivine";
+import { cek } from "./cek";
+
+// Times the abstract machines against the substitution reducer on a term
+// The Krivine machine runs against call_by_name, and the CEK machine against
+// call_by_value, which compute the same terms
+// - term: the Term to evaluate
+// - options: the definitions in scope and the step budget of every run, 1000000 by default
+// = the timings of each machine, in milliseconds
+export function benchmark(term: Term, options: MachineOptions = {}): { krivine: Timing, cek: Timing } {
+  var fuel = options.fuel ?? 1000000;
+  return {
+    krivine: time(term, () => krivine(term, { ...options, fuel }), "call_by_name", { ...options, fuel }),
+    cek: time(term, () => cek(term, { ...options, fuel }), "call_by_value", { ...options, fuel }),
+  };
+}
+
+// Times a machine and the reducer for the same strategy
+// - term: the Term to evaluate
+// - machine: runs the machine on the term
+// - strategy: the strategy the machine implements
+// - options: the definitions in scope and the step budget
+// = the timing of both runs
+function time(term: Term, machine: () => Evaluation, strategy: Strategy, options: MachineOptions): Timing {
+  var start = performance.now();
+  var evaluated = machine();
+  var machine_time = performance.now() - start;
+
+  start = performance.now();
+  var reduced = normalize(term, { strategy, defs: options.defs, fuel: options.fuel, detect_cycles: false });
+  var reducer_time = performance.now() - start;
+
+  return {
+    machine: machine_time,
+    reducer: reducer_time,
+    steps: evaluated.counts.beta,
+    agree: evaluated.$ === "Done" && reduced.$ === "Normal" && equal(evaluated.term, reduced.term),
+  };
+}
@@ -0,0 +1,156 @@
+import { Term } from "../Term/_";
+import { Counts, Env, Evaluation, Head, MachineOptions, Thunk, Value } from "./_";
+import { lookup } from "./lookup";
+
+// The state of one call-by-need evaluation
+// - defs: the top-level definitions
+// - shared: the thunk of each definition unfolded so far
+// - counts: the rules applied so far
+// - fuel: the maximum number of beta steps
+type Machine = { defs: Map<string, Term>, shared: Map<string, Thunk>, counts: Counts, fuel: number };
+
+// Thrown when the machine has used up its beta steps
+const OUT_OF_FUEL = { $: "OutOfFuel" };
+
+// Evaluates a term to normal form with call-by-need
+// Arguments are passed as thunks that are updated with their value the first
+// time they are forced, so that the work of reducing an argument is shared by
+// all its occurrences instead of being copied by substitution. Lambdas are
+// then read back by evaluating their body with the bound variable left abstract.
+// - term: the Term to evaluate
+// - options: the definitions in scope and the beta step budget
+// = the normal form and the number of steps taken, or the steps taken before running out of fuel or stack
+export function call_by_need(term: Term, options: MachineOptions = {}): Evaluation {
+  var machine: Machine = {
+    defs: options.defs ?? new Map(),
+    shared: new Map(),
+    counts: { beta: 0, delta: 0, zeta: 0 },
+    fuel: options.fuel ?? 10000,
+  };
+  try {
+    var normal = read_back(evaluate(term, null, machine), 0, machine);
+    return { $: "Done", term: normal, counts: machine.counts };
+  } catch (e) {
+    if (e === OUT_OF_FUEL) {
+      return { $: "OutOfFuel", counts: machine.counts };
+    }
+    // Evaluation recurses on the host stack, whose exhaustion also ends the run
+    if (e instanceof RangeError) {
+      return { $: "StackOverflow", counts: machine.counts };
+    }
+    throw e;
+  }
+}
+
+// Evaluates a term to weak head normal form
+// - term: the term to evaluate
+// - env: the thunks bound to its variables
+// - machine: the state of the evaluation
+// = the value of the term
+function evaluate(term: Term, env: Env<Thunk>, machine: Machine): Value {
+  while (true) {
+    switch (term.$) {
+      case "Var": {
+        var found = lookup(env, term.index);
+        if (found.$ === "Free") {
+          return neutral({ $: "Free", index: term.index - found.size });
+        }
+        return force(found.entry, machine);
+      }
+      case "Lam": {
+        return { $: "Closure", name: term.name, body: term.body, env };
+      }
+      case "App": {
+        var func = evaluate(term.func, env, machine);
+        var arg: Thunk = { state: { $: "Delayed", term: term.arg, env } };
+        if (func.$ === "Neutral") {
+          return { $: "Neutral", head: func.head, spine: [...func.spine, arg] };
+        }
+        if (machine.counts.beta >= machine.fuel) {
+          throw OUT_OF_FUEL;
+        }
+        machine.counts.beta++;
+        env  = { first: arg, rest: func.env };
+        term = func.body;
+        continue;
+      }
+      case "Let": {
+        machine.counts.zeta++;
+        env  = { first: { state: { $: "Delayed", term: term.value, env } }, rest: env };
+        term = term.body;
+        continue;
+      }
+      case "Ref": {
+        var thunk = machine.shared.get(term.name);
+        if (!thunk) {
+          var def = machine.defs.get(term.name);
+          if (!def) {
+            return neutral({ $: "Ref", name: term.name });
+          }
+          machine.counts.delta++;
+          thunk = { state: { $: "Delayed", term: def, env: null } };
+          machine.shared.set(term.name, thunk);
+        }
+        return force(thunk, machine);
+      }
+    }
+  }
+}
+
+// Gets the value of a thunk, evaluating it the first time only
+// - thunk: the thunk to force, updated with its value
+// - machine: the state of the evaluation
+// = the value of the thunk
+function force(thunk: Thunk, machine: Machine): Value {
+  if (thunk.state.$ === "Delayed") {
+    thunk.state = { $: "Forced", value: evaluate(thunk.state.term, thunk.state.env, machine) };
+  }
+  return thunk.state.value;
+}
+
+// Builds a stuck value without arguments
+// - head: the variable or reference it is stuck on
+// = the value
+function neutral(head: Head): Value {
+  return { $: "Neutral", head, spine: [] };
+}
+
+// Converts a value back into a term in normal form
+// - value: the value to read back
+// - depth: the number of lambdas read back so far
+// - machine: the state of the evaluation
+// = the normal form of the value
+function read_back(value: Value, depth: number, machine: Machine): Term {
+  switch (value.$) {
+    case "Closure": {
+      var bound: Thunk = { state: { $: "Forced", value: neutral({ $: "Level", level: depth }) } };
+      var body = evaluate(value.body, { first: bound, rest: value.env }, machine);
+      return { $: "Lam", name: value.name, body: read_back(body, depth + 1, machine) };
+    }
+    case "Neutral": {
+      var term = read_head(value.head, depth);
+      for (var arg of value.spine) {
+        term = { $: "App", func: term, arg: read_back(force(arg, machine), depth, machine) };
+      }
+      return term;
+    }
+  }
+}
+
+// Converts the head of a stuck value back into a term
+// - head: the head to read back
+// - depth: the number of lambdas read back so far
+// = the corresponding variable or reference
+function read_head(head: Head, depth: number): Term {
+  switch (head.$) {
+    case "Free": {
+      return { $: "Var", index: head.index + depth };
+    }
+    case "Level": {
+      return { $: "Var", index: depth - head.level - 1 };
+    }
+    case "Ref": {
+      return { $: "Ref", name: head.name };
+    }
+  }
+}
@@ -0,0 +1,149 @@
+import { Term } from "../Term/_";
+import { CekValue, Counts, Env, Evaluation, Frame, Head, MachineOptions } from "./_";
+import { lookup } from "./lookup";
+import { read_back } from "./read_back";
+
+// Evaluates a term with the CEK machine, i.e. call-by-value without substitution
+// The machine state is a term (Control), an environment of values indexed by
+// de Bruijn index (Environment), and a stack of frames (Kontinuation). It
+// alternates between evaluating a term and returning a value to the top frame.
+// Applying a free variable gets stuck, as does reduce: the machine then halts,
+// and its whole state is read back into the term reduce would have stopped at.
+// - term: the Term to evaluate
+// - options: the definitions in scope and the beta step budget
+// = the term reached by call_by_value, and the steps taken
+export function cek(term: Term, options: MachineOptions = {}): Evaluation {
+  var defs   = options.defs ?? new Map<string, Term>();
+  var fuel   = options.fuel ?? 10000;
+  var counts: Counts = { beta: 0, delta: 0, zeta: 0 };
+  var env: Env<CekValue> = null;
+  var kont: Frame[] = [];
+
+  while (true) {
+    // Evaluates the control term to a value
+    var value: CekValue;
+    switch (term.$) {
+      case "App": {
+        kont.push({ $: "Arg", term: term.arg, env });
+        term = term.func;
+        continue;
+      }
+      case "Let": {
+        kont.push({ $: "Bind", name: term.name, body: term.body, env });
+        term = term.value;
+        continue;
+      }
+      case "Lam": {
+        value = { $: "Closure", name: term.name, body: term.body, env };
+        break;
+      }
+      case "Var": {
+        var found = lookup(env, term.index);
+        value = found.$ === "Free"
+          ? atom({ $: "Free", index: term.index - found.size })
+          : found.entry;
+        break;
+      }
+      case "Ref": {
+        var def = defs.get(term.name);
+        if (def) {
+          counts.delta++;
+          term = def;
+          env  = null;
+          continue;
+        }
+        value = atom({ $: "Ref", name: term.name });
+        break;
+      }
+    }
+
+    // Returns the value to the top frame
+    var frame = kont.pop();
+    if (!frame) {
+      return { $: "Done", term: read_value(value), counts };
+    }
+    switch (frame.$) {
+      case "Arg": {
+        if (value.$ === "Atom") {
+          return { $: "Done", term: plug({ $: "App", func: read_value(value), arg: read_term(frame.term, frame.env) }, kont), counts };
+        }
+        kont.push({ $: "Fun", func: value });
+        term = frame.term;
+        env  = frame.env;
+        continue;
+      }
+      case "Fun": {
+        if (counts.beta >= fuel) {
+          return { $: "OutOfFuel", counts };
+        }
+        counts.beta++;
+        term = frame.func.body;
+        env  = { first: value, rest: frame.func.env };
+        continue;
+      }
+      case "Bind": {
+        counts.zeta++;
+        term = frame.body;
+        env  = { first: value, rest: frame.env };
+        continue;
+      }
+    }
+  }
+}
+
+// Builds a value for a variable or reference that cannot reduce
+// - head: the variable or reference
+// = the value
+function atom(head: Exclude<Head, { $: "Level" }>): CekValue {
+  return { $: "Atom", head };
+}
+
+// Converts a value back into a plain term
+// - value: the value to read back
+// = the lambda or variable it stands for
+function read_value(value: CekValue): Term {
+  switch (value.$) {
+    case "Closure": {
+      return { $: "Lam", name: value.name, body: read_back(value.body, value.env, read_value, 1) };
+    }
+    case "Atom": {
+      switch (value.head.$) {
+        case "Free":  return { $: "Var", index: value.head.index };
+        case "Ref":   return { $: "Ref", name: value.head.name };
+      }
+    }
+  }
+}
+
+// Converts a term under an environment of values back into a plain term
+// - term: the term to read back
+// - env: the values bound to its variables
+// = the term with its environment substituted in
+function read_term(term: Term, env: Env<CekValue>): Term {
+  return read_back(term, env, read_value);
+}
+
+// Rebuilds the term a stuck machine stands for, from the inside out
+// - term: the stuck term in focus
+// - kont: the pending frames, the innermost one on top
+// = the whole term, with the focus plugged into its context
+function plug(term: Term, kont: Frame[]): Term {
+  for (var i = kont.length - 1; i >= 0; --i) {
+    var frame = kont[i];
+    switch (frame.$) {
+      case "Arg": {
+        term = { $: "App", func: term, arg: read_term(frame.term, frame.env) };
+        break;
+      }
+      case "Fun": {
+        term = { $: "App", func: read_value(frame.func), arg: term };
+        break;
+      }
+      case "Bind": {
+        term = { $: "Let", name: frame.name, value: term, body: read_back(frame.body, frame.env, read_value, 1) };
+        break;
+      }
+    }
+  }
+  return term;
+}
@@ -0,0 +1,28 @@
+import { Term } from "../Term/_";
+import { normalize } from "../Reducer/normalize";
+import { MachineOptions, Sharing } from "./_";
+import { call_by_need } from "./call_by_need";
+
+// Measures the beta steps saved by call-by-need over call-by-name
+// Both evaluations compute the full normal form, with the same fuel but not
+// the same meaning of it: call-by-need spends it on beta steps only, while the
+// normal order run spends it on every step, delta and zeta steps included, so
+// on terms with definitions or lets it may run out of fuel first
+// - term: the Term to evaluate
+// - options: the definitions in scope and the step budget of each run
+// = both outcomes and the difference in beta steps
+export function compare_sharing(term: Term, options: MachineOptions = {}): Sharing {
+  var need = call_by_need(term, options);
+  var name_beta = 0;
+  var name = normalize(term, {
+    strategy: "normal_order",
+    defs: options.defs,
+    fuel: options.fuel,
+    detect_cycles: false,
+    on_step: step => {
+      name_beta += step.redexes.filter(redex => redex.rule === "beta").length;
+    },
+  });
+  var saved = need.$ === "Done" && name.$ === "Normal" ? name_beta - need.counts.beta : null;
+  return { need, name, name_beta, saved };
+}
@@ -0,0 +1,24 @@
+import { Term } from "../Term/_";
+import { equal } from "../Term/equal";
+import { normalize } from "../Reducer/normalize";
+import { CrossCheck, MachineOptions } from "./_";
+import { nbe } from "./nbe";
+
+// Checks that normal order reduction and normalization by evaluation agree on a term
+// - term: the Term to normalize
+// - options: the definitions in scope and the step budget of both runs
+// = whether both found the same normal form
+export function cross_check(term: Term, options: MachineOptions = {}): CrossCheck {
+  var evaluated = nbe(term, options);
+  var reduced = normalize(term, { strategy: "normal_order", defs: options.defs, fuel: options.fuel, detect_cycles: false });
+  if (evaluated.$ === "StackOverflow") {
+    return { $: "StackOverflow", beta: evaluated.counts.beta, reducer: reduced.$ === "Normal" ? reduced.term : null };
+  }
+  if (evaluated.$ !== "Done" || reduced.$ !== "Normal") {
+    return { $: "Unknown" };
+  }
+  if (!equal(evaluated.term, reduced.term)) {
+    return { $: "Disagree", nbe: evaluated.term, reducer: reduced.term };
+  }
+  return { $: "Agree", term: evaluated.term };
+}
@@ -0,0 +1,88 @@
+import { Term } from "../Term/_";
+import { Closure, Counts, Env, Evaluation, MachineOptions } from "./_";
+import { lookup } from "./lookup";
+import { read_back } from "./read_back";
+
+// Evaluates a term with the Krivine machine, i.e. call-by-name without substitution
+// The machine state is a term, an environment of closures indexed by de Bruijn
+// index, and a stack of the arguments not yet consumed. A beta step pops an
+// argument into the environment, so it costs the same whatever the size of the term.
+// Let-bound values are closures too, evaluated again at each use: results agree
+// with call_by_name, which shares them, up to the reduction of those values.
+// - term: the Term to evaluate
+// - options: the definitions in scope and the beta step budget
+// = the weak head normal form reached by call_by_name, and the steps taken
+export function krivine(term: Term, options: MachineOptions = {}): Evaluation {
+  var defs   = options.defs ?? new Map<string, Term>();
+  var fuel   = options.fuel ?? 10000;
+  var counts: Counts = { beta: 0, delta: 0, zeta: 0 };
+  var env: Env<Closure> = null;
+  var stack: Closure[] = [];
+
+  while (true) {
+    switch (term.$) {
+      case "App": {
+        stack.push({ term: term.arg, env });
+        term = term.func;
+        continue;
+      }
+      case "Lam": {
+        var arg = stack.pop();
+        if (!arg) {
+          return { $: "Done", term: read_closure({ term, env }), counts };
+        }
+        if (counts.beta >= fuel) {
+          return { $: "OutOfFuel", counts };
+        }
+        counts.beta++;
+        env  = { first: arg, rest: env };
+        term = term.body;
+        continue;
+      }
+      case "Var": {
+        var found = lookup(env, term.index);
+        if (found.$ === "Free") {
+          return { $: "Done", term: read_spine({ $: "Var", index: term.index - found.size }, stack), counts };
+        }
+        term = found.entry.term;
+        env  = found.entry.env;
+        continue;
+      }
+      case "Let": {
+        counts.zeta++;
+        env  = { first: { term: term.value, env }, rest: env };
+        term = term.body;
+        continue;
+      }
+      case "Ref": {
+        var def = defs.get(term.name);
+        if (!def) {
+          return { $: "Done", term: read_spine(term, stack), counts };
+        }
+        counts.delta++;
+        term = def;
+        env  = null;
+        continue;
+      }
+    }
+  }
+}
+
+// Converts a closure back into a plain term
+// - closure: the closure to read back
+// = its term, with its environment substituted in
+function read_closure(closure: Closure): Term {
+  return read_back(closure.term, closure.env, read_closure);
+}
+
+// Applies a stuck head to the arguments left on the stack
+// - head: the variable or reference the machine is stuck on
+// - stack: the pending arguments, the first one on top
+// = the application of the head to the arguments
+function read_spine(head: Term, stack: Closure[]): Term {
+  var term = head;
+  for (var i = stack.length - 1; i >= 0; --i) {
+    term = { $: "App", func: term, arg: read_closure(stack[i]) };
+  }
+  return term;
+}
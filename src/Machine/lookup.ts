@@ -0,0 +1,17 @@
+import { Env } from "./_";
+
+// Finds the entry bound to a de Bruijn index
+// - env: the environment to search
+// - index: the de Bruijn index of the variable
+// = the entry, or the number of entries in the environment if the variable is free in it
+export function lookup<T>(env: Env<T>, index: number): { $: "Bound", entry: T } | { $: "Free", size: number } {
+  var size = 0;
+  while (env !== null) {
+    if (size === index) {
+      return { $: "Bound", entry: env.first };
+    }
+    env = env.rest;
+    size++;
+  }
+  return { $: "Free", size };
+}
@@ -0,0 +1,165 @@
+import { Term } from "../Term/_";
+import { Counts, Env, Evaluation, Head, Lazy, MachineOptions, Sem } from "./_";
+import { lookup } from "./lookup";
+
+// The state of one normalization by evaluation
+// - defs: the top-level definitions
+// - shared: the value of each definition unfolded so far
+// - counts: the rules applied so far
+// - fuel: the maximum number of beta steps
+type Normalizer = { defs: Map<string, Term>, shared: Map<string, Lazy>, counts: Counts, fuel: number };
+
+// Thrown when the normalizer has used up its beta steps
+const OUT_OF_FUEL = { $: "OutOfFuel" };
+
+// Computes the normal form of a term by evaluation
+// The term is evaluated into host functions, so that beta reduction is a
+// function call, and the value is then quoted back into a term, by applying
+// each function to a fresh variable identified by its de Bruijn level.
+// Arguments are evaluated lazily, so the normal form is found whenever
+// normal order finds it.
+// - term: the Term to normalize
+// - options: the definitions in scope and the beta step budget
+// = the normal form and the steps taken, or the steps taken before running out of fuel or stack
+export function nbe(term: Term, options: MachineOptions = {}): Evaluation {
+  var normalizer: Normalizer = {
+    defs: options.defs ?? new Map(),
+    shared: new Map(),
+    counts: { beta: 0, delta: 0, zeta: 0 },
+    fuel: options.fuel ?? 10000,
+  };
+  try {
+    var normal = quote(evaluate(term, null, normalizer), 0);
+    return { $: "Done", term: normal, counts: normalizer.counts };
+  } catch (e) {
+    if (e === OUT_OF_FUEL) {
+      return { $: "OutOfFuel", counts: normalizer.counts };
+    }
+    // Evaluation recurses on the host stack, whose exhaustion also ends the run
+    if (e instanceof RangeError) {
+      return { $: "StackOverflow", counts: normalizer.counts };
+    }
+    throw e;
+  }
+}
+
+// Evaluates a term into the semantic domain
+// - term: the term to evaluate
+// - env: the values of its variables
+// - normalizer: the state of the normalization
+// = the value of the term
+function evaluate(term: Term, env: Env<Lazy>, normalizer: Normalizer): Sem {
+  switch (term.$) {
+    case "Var": {
+      var found = lookup(env, term.index);
+      if (found.$ === "Free") {
+        return neutral({ $: "Free", index: term.index - found.size });
+      }
+      return found.entry();
+    }
+    case "Lam": {
+      const { body } = term;
+      return { $: "Fun", name: term.name, apply: arg => evaluate(body, { first: arg, rest: env }, normalizer) };
+    }
+    case "App": {
+      return apply(evaluate(term.func, env, normalizer), delay(term.arg, env, normalizer), normalizer);
+    }
+    case "Let": {
+      normalizer.counts.zeta++;
+      return evaluate(term.body, { first: delay(term.value, env, normalizer), rest: env }, normalizer);
+    }
+    case "Ref": {
+      var shared = normalizer.shared.get(term.name);
+      if (!shared) {
+        var def = normalizer.defs.get(term.name);
+        if (!def) {
+          return neutral({ $: "Ref", name: term.name });
+        }
+        normalizer.counts.delta++;
+        shared = delay(def, null, normalizer);
+        normalizer.shared.set(term.name, shared);
+      }
+      return shared();
+    }
+  }
+}
+
+// Applies a value to an argument
+// - func: the value in function position
+// - arg: the argument, not evaluated yet
+// - normalizer: the state of the normalization
+// = the value of the application
+function apply(func: Sem, arg: Lazy, normalizer: Normalizer): Sem {
+  switch (func.$) {
+    case "Fun": {
+      if (normalizer.counts.beta >= normalizer.fuel) {
+        throw OUT_OF_FUEL;
+      }
+      normalizer.counts.beta++;
+      return func.apply(arg);
+    }
+    case "Neutral": {
+      return { $: "Neutral", head: func.head, spine: [...func.spine, arg] };
+    }
+  }
+}
+
+// Suspends the evaluation of a term until its value is first needed
+// - term: the term to evaluate
+// - env: the values of its variables
+// - normalizer: the state of the normalization
+// = a function computing the value once, and returning it from then on
+function delay(term: Term, env: Env<Lazy>, normalizer: Normalizer): Lazy {
+  var value: Sem | null = null;
+  return () => {
+    if (value === null) {
+      value = evaluate(term, env, normalizer);
+    }
+    return value;
+  };
+}
+
+// Builds a stuck value without arguments
+// - head: the variable or reference it is stuck on
+// = the value
+function neutral(head: Head): Sem {
+  return { $: "Neutral", head, spine: [] };
+}
+
+// Converts a value back into a term in normal form
+// - value: the value to quote
+// - depth: the number of lambdas quoted so far, which is the level of the next variable
+// = the normal form of the value
+function quote(value: Sem, depth: number): Term {
+  switch (value.$) {
+    case "Fun": {
+      var bound = neutral({ $: "Level", level: depth });
+      return { $: "Lam", name: value.name, body: quote(value.apply(() => bound), depth + 1) };
+    }
+    case "Neutral": {
+      var term = quote_head(value.head, depth);
+      for (var arg of value.spine) {
+        term = { $: "App", func: term, arg: quote(arg(), depth) };
+      }
+      return term;
+    }
+  }
+}
+
+// Converts the head of a stuck value back into a term
+// - head: the head to quote
+// - depth: the number of lambdas quoted so far
+// = the corresponding variable or reference
+function quote_head(head: Head, depth: number): Term {
+  switch (head.$) {
+    case "Free": {
+      return { $: "Var", index: head.index + depth };
+    }
+    case "Level": {
+      return { $: "Var", index: depth - head.level - 1 };
+    }
+    case "Ref": {
+      return { $: "Ref", name: head.name };
+    }
+  }
+}
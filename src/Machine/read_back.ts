@@ -0,0 +1,48 @@
+import { Term } from "../Term/_";
+import { shift } from "../Reducer/shift";
+import { Env } from "./_";
+import { lookup } from "./lookup";
+
+// Converts a term under an environment back into a plain term
+// Each bound variable is replaced by the term its entry reads back to, while
+// variables free in the environment keep their index relative to the top level
+// - term: the term to read back
+// - env: the entries bound to its variables
+// - read_entry: reads back an entry of the environment into a closed-over term
+// - depth: the number of binders entered inside the term
+// = the term with its environment substituted in
+export function read_back<T>(term: Term, env: Env<T>, read_entry: (entry: T) => Term, depth: number = 0): Term {
+  switch (term.$) {
+    case "Var": {
+      if (term.index < depth) {
+        return term;
+      }
+      var found = lookup(env, term.index - depth);
+      if (found.$ === "Free") {
+        return { $: "Var", index: term.index - found.size };
+      }
+      return shift(read_entry(found.entry), depth, 0);
+    }
+    case "Lam": {
+      return { $: "Lam", name: term.name, body: read_back(term.body, env, read_entry, depth + 1) };
+    }
+    case "App": {
+      return {
+        $: "App",
+        func: read_back(term.func, env, read_entry, depth),
+        arg: read_back(term.arg, env, read_entry, depth),
+      };
+    }
+    case "Let": {
+      return {
+        $: "Let",
+        name: term.name,
+        value: read_back(term.value, env, read_entry, depth),
+        body: read_back(term.body, env, read_entry, depth + 1),
+      };
+    }
+    case "Ref": {
+      return term;
+    }
+  }
+}
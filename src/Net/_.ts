@@ -0,0 +1,67 @@
+import { Term } from "../Term/_";
+
+// Represents an agent of an interaction net
+// Every node has three ports: the principal port 0, and the auxiliary ports 1 and 2
+// - Con: a lambda or an application, which are the same agent. A lambda is
+//   connected to its user through port 0, to its variable through port 1 and
+//   to its body through port 2; an application is connected to its function
+//   through port 0, to its argument through port 1 and to its user through port 2
+// - Dup: a fan sharing its principal port between its two auxiliary ports;
+//   fans with the same label annihilate, fans with different labels commute
+// - Era: an eraser, which deletes whatever it meets
+// - Root: the entry point of the net, connected to the term through port 1
+// - Free: a free variable, by its index at the top level, connected through port 1
+// - Ref: a reference to an unknown definition, connected through port 1
+// Root, Free and Ref only use an auxiliary port, so they never interact
+export type Agent
+  = { $: "Con" }
+  | { $: "Dup", label: number }
+  | { $: "Era" }
+  | { $: "Root" }
+  | { $: "Free", index: number }
+  | { $: "Ref", name: string };
+
+// Represents a port, as 3 * node + slot
+export type Port = number;
+
+// Represents an interaction net
+// - agents: the agent of each node, or null once the node is consumed
+// - links: the port each port is connected to
+// - active: the pairs of principal ports connected to each other, yet to interact
+// - root: the Root node
+// - labels: the number of fan labels used so far
+export type Net = {
+  agents: Array<Agent | null>,
+  links: Port[],
+  active: Array<[Port, Port]>,
+  root: number,
+  labels: number,
+};
+
+// Counts the interactions performed while reducing a net
+// - beta: the lambda-application annihilations, each being a beta step
+// - annihilate: the annihilations of fans with the same label
+// - commute: the commutations of two different agents, which copy them
+// - erase: the interactions with an eraser
+// - total: all the interactions
+export type Interactions = { beta: number, annihilate: number, commute: number, erase: number, total: number };
+
+// Represents how optimal reduction ended
+// - Done: the net reached its normal form, which was read back into a term
+// - OutOfFuel: the reduction was stopped after using up its interactions
+// - Unreadable: the normal net does not encode a term, which happens for terms
+//   outside the fragment the abstract algorithm is correct on
+// - StackOverflow: reading the normal net back exhausted the host stack, either
+//   because the term is too deep or because the net is cyclic, which also
+//   happens outside that fragment
+export type Optimal
+  = { $: "Done", term: Term, interactions: Interactions }
+  | { $: "OutOfFuel", interactions: Interactions }
+  | { $: "Unreadable", interactions: Interactions }
+  | { $: "StackOverflow", interactions: Interactions };
+
+// Compares optimal reduction with normal order reduction on the same term
+// - optimal: the outcome of optimal reduction
+// - beta_steps: the beta steps taken by normal order reduction, or null if it did not finish
+// - agree: whether optimal reduction computed the expected normal form, or null if unknown
+export type OptimalComparison = { optimal: Optimal, beta_steps: number | null, agree: boolean | null };
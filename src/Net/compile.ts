@@ -0,0 +1,81 @@
+import { Term } from "../Term/_";
+import { occurrences } from "../Term/occurrences";
+import { Net, Port } from "./_";
+import { add_node, empty_net, link, port } from "./net";
+
+// Compiles a term into an interaction net
+// Each lambda is a Con node whose variable port is shared between the
+// occurrences of its variable by a tree of fans carrying a label of its own,
+// or erased if the variable is unused. Local definitions are compiled as
+// applied lambdas, and references must have been inlined beforehand.
+// - term: the Term to compile
+// = the net, whose Root node is connected to the term
+export function compile(term: Term): Net {
+  var net = empty_net();
+  build(net, term, [], port(net.root, 1));
+  return net;
+}
+
+// Builds the nodes of a term and connects its result to a port
+// - net: the net, updated in place
+// - term: the term to build
+// - scope: for each enclosing binder, innermost last, the ports its
+//   variable's occurrences remain to be connected to
+// - out: the port consuming the term's result
+function build(net: Net, term: Term, scope: Port[][], out: Port): void {
+  switch (term.$) {
+    case "Var": {
+      if (term.index < scope.length) {
+        link(net, scope[scope.length - 1 - term.index].pop()!, out);
+      } else {
+        link(net, port(add_node(net, { $: "Free", index: term.index - scope.length }), 1), out);
+      }
+      return;
+    }
+    case "Lam": {
+      var lam = add_node(net, { $: "Con" });
+      link(net, port(lam, 0), out);
+      scope.push(share(net, port(lam, 1), occurrences(0, term.body)));
+      build(net, term.body, scope, port(lam, 2));
+      scope.pop();
+      return;
+    }
+    case "App": {
+      var app = add_node(net, { $: "Con" });
+      link(net, port(app, 2), out);
+      build(net, term.func, scope, port(app, 0));
+      build(net, term.arg, scope, port(app, 1));
+      return;
+    }
+    case "Let": {
+      build(net, { $: "App", func: { $: "Lam", name: term.name, body: term.body }, arg: term.value }, scope, out);
+      return;
+    }
+    case "Ref": {
+      link(net, port(add_node(net, { $: "Ref", name: term.name }), 1), out);
+      return;
+    }
+  }
+}
+
+// Shares a variable between its occurrences
+// - net: the net, updated in place
+// - source: the variable port of the binder
+// - count: the number of occurrences
+// = one port per occurrence
+function share(net: Net, source: Port, count: number): Port[] {
+  if (count === 0) {
+    link(net, port(add_node(net, { $: "Era" }), 0), source);
+    return [];
+  }
+  var label = ++net.labels;
+  var ports: Port[] = [];
+  for (var i = 1; i < count; ++i) {
+    var dup = add_node(net, { $: "Dup", label });
+    link(net, port(dup, 0), source);
+    ports.push(port(dup, 1));
+    source = port(dup, 2);
+  }
+  ports.push(source);
+  return ports;
+}
@@ -0,0 +1,61 @@
+import { Agent, Net, Port } from "./_";
+
+// Creates a net made of its Root node only
+// = the empty net
+export function empty_net(): Net {
+  var net: Net = { agents: [], links: [], active: [], root: 0, labels: 0 };
+  net.root = add_node(net, { $: "Root" });
+  return net;
+}
+
+// Adds a node to a net, with its ports unconnected
+// - net: the net, updated in place
+// - agent: the agent of the node
+// = the index of the new node
+export function add_node(net: Net, agent: Agent): number {
+  var node = net.agents.length;
+  net.agents.push(agent);
+  net.links.push(-1, -1, -1);
+  return node;
+}
+
+// Gets the port of a node
+// - node: the index of the node
+// - slot: 0 for the principal port, 1 or 2 for the auxiliary ports
+// = the port
+export function port(node: number, slot: number): Port {
+  return node * 3 + slot;
+}
+
+// Gets the node a port belongs to
+// - p: the port
+// = the index of its node
+export function node_of(p: Port): number {
+  return Math.floor(p / 3);
+}
+
+// Gets the slot of a port within its node
+// - p: the port
+// = 0 for a principal port, 1 or 2 for an auxiliary port
+export function slot_of(p: Port): number {
+  return p % 3;
+}
+
+// Connects two ports, recording a new active pair if both are principal ports of interacting agents
+// - net: the net, updated in place
+// - a: a port
+// - b: the port to connect it to
+export function link(net: Net, a: Port, b: Port): void {
+  net.links[a] = b;
+  net.links[b] = a;
+  if (slot_of(a) === 0 && slot_of(b) === 0 && interacts(net.agents[node_of(a)]) && interacts(net.agents[node_of(b)])) {
+    net.active.push([a, b]);
+  }
+}
+
+// Checks if an agent takes part in interactions
+// - agent: the agent of a node, or null for a consumed node
+// = true for lambdas, applications, fans and erasers
+function interacts(agent: Agent | null): boolean {
+  return agent !== null && (agent.$ === "Con" || agent.$ === "Dup" || agent.$ === "Era");
+}
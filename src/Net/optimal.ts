@@ -0,0 +1,63 @@
+import { Term } from "../Term/_";
+import { equal } from "../Term/equal";
+import { inline_refs } from "../Program/inline_refs";
+import { normalize } from "../Reducer/normalize";
+import { nbe } from "../Machine/nbe";
+import { MachineOptions } from "../Machine/_";
+import { Optimal, OptimalComparison } from "./_";
+import { compile } from "./compile";
+import { reduce_net } from "./reduce_net";
+import { read_net } from "./read_net";
+
+// Normalizes a term by optimal reduction of its interaction net
+// Sharing is never undone: a redex is reduced once for all its copies, so the
+// number of beta interactions can be exponentially smaller than the number of
+// beta steps of any substitution-based strategy. This is Lamping's abstract
+// algorithm, without the oracle: it is correct on terms typable in elementary
+// affine logic, which covers Church numerals arithmetic, but may compute a
+// wrong or unreadable result on others.
+// - term: the Term to normalize
+// - options: the definitions in scope, inlined first, and the interaction budget
+// = the normal form and the interactions performed
+export function optimal(term: Term, options: MachineOptions = {}): Optimal {
+  var net = compile(options.defs ? inline_refs(term, options.defs) : term);
+  var { interactions, normal } = reduce_net(net, options.fuel ?? 1000000);
+  if (!normal) {
+    return { $: "OutOfFuel", interactions };
+  }
+  try {
+    var normal_form = read_net(net);
+  } catch (e) {
+    if (e instanceof RangeError) {
+      return { $: "StackOverflow", interactions };
+    }
+    throw e;
+  }
+  if (!normal_form) {
+    return { $: "Unreadable", interactions };
+  }
+  return { $: "Done", term: normal_form, interactions };
+}
+
+// Compares the interactions of optimal reduction with the beta steps of normal order reduction
+// The result is checked against normal order, or normalization by evaluation when normal order runs out of fuel
+// - term: the Term to normalize
+// - options: the definitions in scope, and the budget of every run
+// = the outcome of optimal reduction, the beta steps of normal order, and whether the results agree
+export function compare_optimal(term: Term, options: MachineOptions = {}): OptimalComparison {
+  var result = optimal(term, options);
+  var beta_steps = 0;
+  var reduced = normalize(term, {
+    strategy: "normal_order",
+    defs: options.defs,
+    fuel: options.fuel,
+    detect_cycles: false,
+    on_step: step => {
+      beta_steps += step.redexes.filter(redex => redex.rule === "beta").length;
+    },
+  });
+  var checked = nbe(term, options);
+  var expected = reduced.$ === "Normal" ? reduced.term : checked.$ === "Done" ? checked.term : null;
+  var agree = expected && result.$ === "Done" ? equal(result.term, expected) : null;
+  return { optimal: result, beta_steps: reduced.$ === "Normal" ? beta_steps : null, agree };
+}
@@ -0,0 +1,93 @@
+import { Term } from "../Term/_";
+import { Net, Port } from "./_";
+import { node_of, port, slot_of } from "./net";
+
+// Thrown when a net does not encode a term
+const UNREADABLE = { $: "Unreadable" };
+
+// Reads a term back from a net in normal form
+// The net is walked from its root. A Con node entered through its principal
+// port is a lambda, through its variable port a variable, and through its
+// result port an application. A fan entered through an auxiliary port is
+// left through its principal port, remembering which side it was entered
+// from; the next fan of the same label entered through its principal port is
+// left through that same side.
+// - net: the net to read
+// = the term, or null if the net does not encode one
+export function read_net(net: Net): Term | null {
+  try {
+    return read(net, port(net.root, 1), 0, new Map(), new Map());
+  } catch (e) {
+    // The walk recurses on the host stack: its exhaustion is left to the caller
+    if (e === UNREADABLE) {
+      return null;
+    }
+    throw e;
+  }
+}
+
+// Reads the term connected to a port
+// - net: the net to read
+// - from: the port consuming the term
+// - depth: the number of lambdas entered
+// - binders: the depth of each lambda entered, by node
+// - exits: for each fan label, the sides the fans of that label were entered from
+// = the term
+function read(net: Net, from: Port, depth: number, binders: Map<number, number>, exits: Map<number, number[]>): Term {
+  var p = net.links[from];
+  var node = node_of(p);
+  var slot = slot_of(p);
+  var agent = net.agents[node];
+  switch (agent?.$) {
+    case "Con": {
+      switch (slot) {
+        case 0: {
+          binders.set(node, depth);
+          var body = read(net, port(node, 2), depth + 1, binders, exits);
+          binders.delete(node);
+          return { $: "Lam", body };
+        }
+        case 1: {
+          var binder = binders.get(node);
+          if (binder === undefined) {
+            throw UNREADABLE;
+          }
+          return { $: "Var", index: depth - binder - 1 };
+        }
+        default: {
+          return {
+            $: "App",
+            func: read(net, port(node, 0), depth, binders, exits),
+            arg: read(net, port(node, 1), depth, binders, exits),
+          };
+        }
+      }
+    }
+    case "Dup": {
+      var stack = exits.get(agent.label) ?? [];
+      exits.set(agent.label, stack);
+      if (slot === 0) {
+        var exit = stack.pop();
+        if (exit === undefined) {
+          throw UNREADABLE;
+        }
+        var term = read(net, port(node, exit), depth, binders, exits);
+        stack.push(exit);
+        return term;
+      }
+      stack.push(slot);
+      var shared = read(net, port(node, 0), depth, binders, exits);
+      stack.pop();
+      return shared;
+    }
+    case "Free": {
+      return { $: "Var", index: agent.index + depth };
+    }
+    case "Ref": {
+      return { $: "Ref", name: agent.name };
+    }
+    default: {
+      throw UNREADABLE;
+    }
+  }
+}
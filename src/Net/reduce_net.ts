@@ -0,0 +1,88 @@
+import { Agent, Interactions, Net, Port } from "./_";
+import { add_node, link, node_of, port } from "./net";
+
+// Reduces a net until no active pair is left
+// - net: the net, updated in place
+// - fuel: the maximum number of interactions
+// = the interactions performed, and whether the net reached its normal form
+export function reduce_net(net: Net, fuel: number): { interactions: Interactions, normal: boolean } {
+  var interactions: Interactions = { beta: 0, annihilate: 0, commute: 0, erase: 0, total: 0 };
+  while (net.active.length > 0) {
+    if (interactions.total >= fuel) {
+      return { interactions, normal: false };
+    }
+    var [a, b] = net.active.pop()!;
+    if (net.links[a] !== b) {
+      continue;
+    }
+    interact(net, node_of(a), node_of(b), interactions);
+    interactions.total++;
+  }
+  return { interactions, normal: true };
+}
+
+// Rewrites an active pair
+// - net: the net, updated in place
+// - a: a node whose principal port is connected to b's
+// - b: the other node of the pair
+// - interactions: the counters, updated in place
+function interact(net: Net, a: number, b: number, interactions: Interactions): void {
+  var x = net.agents[a]!;
+  var y = net.agents[b]!;
+  if (x.$ === "Era" || y.$ === "Era") {
+    interactions.erase++;
+    erase(net, x.$ === "Era" ? b : a);
+  } else if (x.$ === "Con" && y.$ === "Con") {
+    interactions.beta++;
+    annihilate(net, a, b);
+  } else if (x.$ === "Dup" && y.$ === "Dup" && x.label === y.label) {
+    interactions.annihilate++;
+    annihilate(net, a, b);
+  } else {
+    interactions.commute++;
+    commute(net, a, x, b, y);
+  }
+  net.agents[a] = null;
+  net.agents[b] = null;
+}
+
+// Connects the auxiliary ports of two nodes pairwise
+// Peers are read after each link, so that wires between the two nodes are followed
+// - net: the net, updated in place
+// - a: a node
+// - b: the node it annihilates with
+function annihilate(net: Net, a: number, b: number): void {
+  link(net, net.links[port(a, 1)], net.links[port(b, 1)]);
+  link(net, net.links[port(a, 2)], net.links[port(b, 2)]);
+}
+
+// Lets two different agents pass through each other, copying both
+// - net: the net, updated in place
+// - a: a node, whose agent is x
+// - b: the node it commutes with, whose agent is y
+function commute(net: Net, a: number, x: Agent, b: number, y: Agent): void {
+  var y1 = add_node(net, y);
+  var y2 = add_node(net, y);
+  var x1 = add_node(net, x);
+  var x2 = add_node(net, x);
+  link(net, port(y1, 1), port(x1, 1));
+  link(net, port(y1, 2), port(x2, 1));
+  link(net, port(y2, 1), port(x1, 2));
+  link(net, port(y2, 2), port(x2, 2));
+  link(net, port(y1, 0), net.links[port(a, 1)]);
+  link(net, port(y2, 0), net.links[port(a, 2)]);
+  link(net, port(x1, 0), net.links[port(b, 1)]);
+  link(net, port(x2, 0), net.links[port(b, 2)]);
+}
+
+// Erases a node, propagating erasers to its auxiliary ports
+// - net: the net, updated in place
+// - node: the node met by an eraser
+function erase(net: Net, node: number): void {
+  if (net.agents[node]!.$ === "Era") {
+    return;
+  }
+  for (var slot = 1; slot <= 2; ++slot) {
+    link(net, port(add_node(net, { $: "Era" }), 0), net.links[port(node, slot)]);
+  }
+}
@@ -1,7 +1,73 @@
 import { Term } from "../Term/_";
 
 // Represents a parser function that takes a string input
-// and returns a Term if successful, or null if parsing fails
+// and returns a Term if successful, or a ParseError if parsing fails
 // - input: the string to be parsed
-// = the parsed Term if successful, or null if parsing fails
-export type Parser = (input: string) => Term | null;
+// - context: the names already known, if any
+// = the parse result
+export type Parser = (input: string, context?: ParseContext) => ParseResult;
+
+// Represents the names known to a parser before it starts
+// - free: the names of already known free variables
+// - defs: the names of top-level definitions, parsed as references
+// - closed: whether unbound names are errors rather than free variables
+export type ParseContext = {
+  free?: string[],
+  defs?: Set<string>,
+  closed?: boolean,
+};
+
+// Represents the result of parsing
+// - Ok: the parsed Term, and the names of its free variables: at binder
+//   depth d, the variable free[i] has index d + i; the source span of each
+//   node of the term by the path_key of the node, and the type annotations
+//   it was written with
+// - Err: why and where parsing failed
+export type ParseResult
+  = { $: "Ok", term: Term, free: string[], spans: Map<string, Span>, annotations: Annotations }
+  | { $: "Err", error: ParseError };
+
+// Represents a simple type as written in an annotation, e.g. (a -> b) -> c
+// The checker's Type has the same shape, so annotations are used as they are
+// - TVar: a type variable, numbered in order of first appearance
+// - Arrow: the type of functions from one type to another
+export type TypeSyntax
+  = { $: "TVar", id: number }
+  | { $: "Arrow", from: TypeSyntax, to: TypeSyntax };
+
+// Represents the type annotations written in a term's source, keyed by the
+// path_key of the annotated node
+// Type variables in annotations are rigid: a stands for one unknown type, not any type
+// - binders: the types of the binders of annotated lambdas, λ(x : T) M
+// - terms: the types ascribed to subterms, (M : T), innermost first
+// - names: the source name of each type variable, by number
+export type Annotations = {
+  binders: Map<string, TypeSyntax>,
+  terms: Map<string, TypeSyntax[]>,
+  names: Map<number, string>,
+};
+
+// Represents how far a parser has read its input
+// - input: the string being parsed
+// - index: the offset of the next character to read
+export type Cursor = { input: string, index: number };
+
+// Represents where a subterm was written in the input
+// - start: the offset of its first character
+// - end: the offset just past its last character
+export type Span = { start: number, end: number };
+
+// Represents a parse failure
+// - index: the offset of the offending character in the input
+// - line: the line of the offending character, starting at 1
+// - column: the column of the offending character, starting at 1
+// - expected: the tokens that would have been accepted at that point
+// - message: a human-readable description of the failure
+export type ParseError = {
+  $: "ParseError",
+  index: number,
+  line: number,
+  column: number,
+  expected: string[],
+  message: string,
+};
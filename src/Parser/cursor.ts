@@ -0,0 +1,49 @@
+import { Cursor } from "./_";
+import { parse_error } from "./error";
+
+// Skips whitespace
+// - cursor: the parser's position, moved past the whitespace
+export function skip_whitespace(cursor: Cursor): void {
+  while (cursor.index < cursor.input.length && /\s/.test(cursor.input[cursor.index])) {
+    cursor.index++;
+  }
+}
+
+// Reads the identifier at the cursor, such as x, succ, x1 or f', without consuming it
+// - cursor: the parser's position
+// = the identifier, or null if none starts there
+export function peek_identifier(cursor: Cursor): string | null {
+  const match = /^[a-zA-Z_][a-zA-Z0-9_']*/.exec(cursor.input.slice(cursor.index));
+  return match ? match[0] : null;
+}
+
+// Parses an identifier such as x, succ, x1 or f'
+// - cursor: the parser's position, moved past the identifier
+// = the identifier, or null if none starts there
+export function parse_identifier(cursor: Cursor): string | null {
+  const name = peek_identifier(cursor);
+  if (name) {
+    cursor.index += name.length;
+  }
+  return name;
+}
+
+// Consumes an expected token, after any whitespace
+// - cursor: the parser's position, moved past the token
+// - token: the token
+export function expect(cursor: Cursor, token: string): void {
+  skip_whitespace(cursor);
+  if (!cursor.input.startsWith(token, cursor.index)) {
+    return fail(cursor, [token], `expected '${token}'`);
+  }
+  cursor.index += token.length;
+}
+
+// Aborts parsing with an error at the given offset
+// - cursor: the parser's position
+// - expected: the tokens that would have been accepted
+// - message: a description of the failure
+// - at: the offset of the offending character, the cursor's by default
+export function fail(cursor: Cursor, expected: string[], message: string, at: number = cursor.index): never {
+  throw parse_error(cursor.input, at, expected, message);
+}
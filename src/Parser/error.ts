@@ -0,0 +1,58 @@
+import { ParseError, Span } from "./_";
+
+// Builds a parse error, locating the offending offset in the input
+// - input: the string being parsed
+// - index: the offset of the offending character
+// - expected: the tokens that would have been accepted
+// - message: a human-readable description of the failure
+// = the ParseError
+export function parse_error(input: string, index: number, expected: string[], message: string): ParseError {
+  var lines  = input.slice(0, index).split("\n");
+  var line   = lines.length;
+  var column = lines[lines.length - 1].length + 1;
+  return { $: "ParseError", index, line, column, expected, message };
+}
+
+// Checks if a thrown value is a parse error
+// - value: the thrown value
+// = true if the value is a ParseError
+export function is_parse_error(value: unknown): value is ParseError {
+  return typeof value === "object" && value !== null && (value as ParseError).$ === "ParseError";
+}
+
+// Renders a parse error with a caret under the offending character
+// - input: the string that failed to parse
+// - error: the ParseError to render
+// = a multi-line description of the error
+export function show_parse_error(input: string, error: ParseError): string {
+  var source = input.split("\n")[error.line - 1] ?? "";
+  var lines  = [
+    `Parse error at ${error.line}:${error.column}: ${error.message}`,
+    `  ${source}`,
+    `  ${" ".repeat(error.column - 1)}^`,
+  ];
+  if (error.expected.length > 0) {
+    lines.push(`Expected: ${error.expected.join(", ")}`);
+  }
+  return lines.join("\n");
+}
+
+// Renders a message about a span of the input, underlining it with carets
+// Spans over several lines are underlined up to the end of their first line
+// - input: the string that was parsed
+// - span: the part of the input to underline
+// - kind: the kind of error, e.g. Type error
+// - message: a human-readable description of the error
+// = a multi-line description of the error
+export function show_span(input: string, span: Span, kind: string, message: string): string {
+  var lines  = input.slice(0, span.start).split("\n");
+  var line   = lines.length;
+  var column = lines[lines.length - 1].length + 1;
+  var source = input.split("\n")[line - 1] ?? "";
+  var width  = Math.max(1, Math.min(span.end - span.start, source.length - column + 1));
+  return [
+    `${kind} at ${line}:${column}: ${message}`,
+    `  ${source}`,
+    `  ${" ".repeat(column - 1)}${"^".repeat(width)}`,
+  ].join("\n");
+}
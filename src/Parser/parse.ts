@@ -1,97 +1,310 @@
-import { Parser } from "./_";
+import { Cursor, ParseContext, Parser, ParseResult, Span, TypeSyntax } from "./_";
 import { Term } from "../Term/_";
+import { church_numeral } from "../Term/church";
+import { key_by_path } from "../Term/path_key";
+import { is_parse_error } from "./error";
+import { fail, parse_identifier, peek_identifier, skip_whitespace } from "./cursor";
 
 // A parser for lambda terms using de Bruijn indices
+// Unbound names that are not definitions become free variables, numbered
+// past the enclosing binders in order of first appearance
+// Terms may be annotated with simple types, (M : T) and λ(x : T) M; the
+// annotations and the source span of every node are returned beside the term.
+// Numbers such as 3 stand for Church numerals, λf x. f (f (f x)), up to MAX_NUMERAL
 // - input: the string to be parsed
-// = the parsed Term if successful, or null if parsing fails
-export const parse: Parser = (input: string): Term | null => {
-  let index = 0;
+// - context.free: the names of the free variables, extended with any unknown name met
+// - context.defs: the names of the definitions, parsed as references
+// - context.closed: whether unbound names are errors rather than free variables
+// = the parsed Term and its free variable names, or a ParseError locating the failure
+export const parse: Parser = (input: string, context: ParseContext = {}): ParseResult => {
+  const cursor: Cursor = { input, index: 0 };
   let bound_vars: string[] = [];
+  let free_vars: string[] = [...(context.free ?? [])];
+  // What is recorded of each node while parsing, keyed by path at the end
+  let spans = new Map<Term, Span>();
+  let binder_types = new Map<Term, TypeSyntax>();
+  let ascribed = new Map<Term, TypeSyntax[]>();
+  let type_vars: string[] = [];
 
   // Helper function to parse a single term
-  const parse_term = (): Term | null => {
-    skip_whitespace();
-    
-    if (index >= input.length) {
-      return null;
-    }
+  const parse_term = (): Term => {
+    skip_whitespace(cursor);
 
+    const start = cursor.index;
     let term = parse_atom();
-    if (!term) return null;
 
     // Parse applications
-    while (index < input.length) {
-      skip_whitespace();
-      if (index >= input.length || input[index] === ')') break;
+    while (true) {
+      skip_whitespace(cursor);
+      if (!starts_atom()) break;
       const arg = parse_atom();
-      if (!arg) break;
-      term = { $: "App", func: term, arg };
+      term = located({ $: "App", func: term, arg }, start);
     }
 
     return term;
   };
 
-  // Helper function to parse an atom (variable, lambda, or parenthesized term)
-  const parse_atom = (): Term | null => {
-    skip_whitespace();
-    
-    if (index >= input.length) {
-      return null;
-    }
-
-    switch (input[index]) {
-      case 'λ': {
-        // Lambda abstraction
-        index++; // Skip 'λ'
-        skip_whitespace();
-        if (!is_variable(input[index])) return null;
-        const var_name = input[index];
-        index++; // Skip variable
-        bound_vars.push(var_name);
+  // Helper function to parse an atom (variable, lambda, let, or parenthesized term)
+  const parse_atom = (): Term => {
+    skip_whitespace(cursor);
+
+    if (cursor.index >= input.length) {
+      return fail(cursor, ATOM, "unexpected end of input");
+    }
+
+    switch (input[cursor.index]) {
+      case 'λ':
+      case '\\': {
+        // Lambda abstraction, with one or more binders
+        const start = cursor.index;
+        cursor.index++; // Skip 'λ' or '\\'
+        skip_whitespace(cursor);
+        const binders: Array<{ name: string, type?: TypeSyntax }> = input[cursor.index] === '(' ? parse_typed_binders() : parse_binders().map(name => ({ name }));
+        bound_vars.push(...binders.map(binder => binder.name));
         const body = parse_term();
-        bound_vars.pop();
-        return body ? { $: "Lam", body } : null;
+        bound_vars.splice(bound_vars.length - binders.length);
+        // Desugar λx y z. body into λx λy λz body
+        return binders.reduceRight<Term>((body, { name, type }) => {
+          const lam: Term = located({ $: "Lam", name, body }, start);
+          if (type) binder_types.set(lam, type);
+          return lam;
+        }, body);
       }
       case '(': {
-        // Parenthesized expression
-        index++; // Skip '('
+        // Parenthesized expression, possibly with a type annotation (M : T)
+        cursor.index++; // Skip '('
         const term = parse_term();
-        if (!term) return null;
-        skip_whitespace();
-        if (input[index] !== ')') return null;
-        index++; // Skip ')'
+        skip_whitespace(cursor);
+        if (input[cursor.index] === ':') {
+          cursor.index++; // Skip ':'
+          const type = parse_type();
+          ascribed.set(term, [...(ascribed.get(term) ?? []), type]);
+          skip_whitespace(cursor);
+        }
+        if (input[cursor.index] !== ')') {
+          return fail(cursor, [...ATOM, ":", ")"], "missing ')'");
+        }
+        cursor.index++; // Skip ')'
         return term;
       }
       default: {
+        // Local definition
+        if (peek_identifier(cursor) === "let") {
+          return parse_let();
+        }
+
+        // Church numeral
+        const start = cursor.index;
+        const digits = /^[0-9]+/.exec(input.slice(cursor.index));
+        if (digits) {
+          const value = parseInt(digits[0], 10);
+          if (value > MAX_NUMERAL) {
+            return fail(cursor, [], `numerals above ${MAX_NUMERAL} are not supported`);
+          }
+          cursor.index += digits[0].length;
+          return located(church_numeral(value), start);
+        }
+
         // Variable
-        if (is_variable(input[index])) {
-          const var_name = input[index];
-          const var_index = bound_vars.lastIndexOf(var_name);
-          if (var_index === -1) return null; // Unbound variable
-          index++;
-          return { $: "Var", index: bound_vars.length - var_index - 1 };
+        const var_name = parse_identifier(cursor);
+        const end = cursor.index;
+        if (!var_name) {
+          return fail(cursor, ATOM, `unexpected '${input[cursor.index]}'`);
+        }
+        if (KEYWORDS.includes(var_name)) {
+          return fail(cursor, ATOM, `unexpected keyword '${var_name}'`, start);
+        }
+        const var_index = bound_vars.lastIndexOf(var_name);
+        if (var_index === -1 && context.defs?.has(var_name)) {
+          // Reference to a definition
+          return located({ $: "Ref", name: var_name }, start, end);
         }
-        return null;
+        if (var_index === -1 && context.closed) {
+          return fail(cursor, [], `unbound variable '${var_name}'`, start);
+        }
+        if (var_index === -1) {
+          // Free variable
+          if (!free_vars.includes(var_name)) free_vars.push(var_name);
+          return located({ $: "Var", index: bound_vars.length + free_vars.indexOf(var_name) }, start, end);
+        }
+        return located({ $: "Var", index: bound_vars.length - var_index - 1 }, start, end);
       }
     }
   };
 
-  // Helper function to skip whitespace
-  const skip_whitespace = (): void => {
-    while (index < input.length && /\s/.test(input[index])) {
-      index++;
+  // Helper function to parse a local definition: let x = value in body
+  const parse_let = (): Term => {
+    const let_start = cursor.index;
+    cursor.index += "let".length;
+    skip_whitespace(cursor);
+    const start = cursor.index;
+    const name = parse_identifier(cursor);
+    if (!name || KEYWORDS.includes(name)) {
+      return fail(cursor, ["variable"], "expected a name after 'let'", start);
+    }
+    skip_whitespace(cursor);
+    if (input[cursor.index] !== '=') {
+      return fail(cursor, ["="], "expected '=' after the name being defined");
+    }
+    cursor.index++; // Skip '='
+    const value = parse_term();
+    skip_whitespace(cursor);
+    if (peek_identifier(cursor) !== "in") {
+      return fail(cursor, [...ATOM, "in"], "expected 'in' after the value being defined");
+    }
+    cursor.index += "in".length;
+    bound_vars.push(name);
+    const body = parse_term();
+    bound_vars.pop();
+    return located({ $: "Let", name, value, body }, let_start);
+  };
+
+  // Helper function to parse the binders of a lambda
+  // Either a single name followed by the body (λx body), or several names
+  // ended by a dot (λx y z. body); a dot after a single name is also allowed
+  const parse_binders = (): string[] => {
+    skip_whitespace(cursor);
+    const start = cursor.index;
+    const first = parse_identifier(cursor);
+    if (!first || KEYWORDS.includes(first)) {
+      return fail(cursor, ["variable"], "expected a binder name after the lambda", start);
+    }
+    const after_first = cursor.index;
+    const names = [first];
+    while (true) {
+      skip_whitespace(cursor);
+      const name = parse_identifier(cursor);
+      if (!name) break;
+      names.push(name);
+    }
+    if (input[cursor.index] === '.' && !names.some(name => KEYWORDS.includes(name))) {
+      cursor.index++; // Skip '.'
+      return names;
+    }
+    cursor.index = after_first; // No dot: only the first name is a binder
+    return [first];
+  };
+
+  // Helper function to parse the annotated binders of a lambda
+  // Either a single binder followed by the body (λ(x : a) body), or several
+  // ended by a dot (λ(x : a) (y : b). body). A parenthesized name and colon
+  // after a binder always starts another binder, so an annotated body such
+  // as (y : b) must follow a dot
+  const parse_typed_binders = (): Array<{ name: string, type: TypeSyntax }> => {
+    const binders = [parse_typed_binder()];
+    skip_whitespace(cursor);
+    while (TYPED_BINDER.test(input.slice(cursor.index))) {
+      binders.push(parse_typed_binder());
+      skip_whitespace(cursor);
+    }
+    if (input[cursor.index] === '.') {
+      cursor.index++; // Skip '.'
+    } else if (binders.length > 1) {
+      return fail(cursor, ["(", "."], "expected '.' after the binders of a lambda; an annotated body (M : T) also follows the dot");
     }
+    return binders;
   };
 
-  // Helper function to check if a character is a valid variable name
-  const is_variable = (char: string): boolean => {
-    return /[a-z]/.test(char);
+  // Helper function to parse one annotated binder: (x : T)
+  const parse_typed_binder = (): { name: string, type: TypeSyntax } => {
+    cursor.index++; // Skip '('
+    skip_whitespace(cursor);
+    const start = cursor.index;
+    const name = parse_identifier(cursor);
+    if (!name || KEYWORDS.includes(name)) {
+      return fail(cursor, ["variable"], "expected a binder name after the lambda", start);
+    }
+    skip_whitespace(cursor);
+    if (input[cursor.index] !== ':') {
+      return fail(cursor, [":"], "expected ':' and the type of the binder");
+    }
+    cursor.index++; // Skip ':'
+    const type = parse_type();
+    skip_whitespace(cursor);
+    if (input[cursor.index] !== ')') {
+      return fail(cursor, ["->", ")"], "missing ')'");
+    }
+    cursor.index++; // Skip ')'
+    return { name, type };
   };
 
-  // Parse the entire input
-  const result = parse_term();
-  skip_whitespace();
+  // Helper function to parse a simple type: a, a -> b, (a -> b) -> c
+  // Arrows, also written →, associate to the right
+  const parse_type = (): TypeSyntax => {
+    skip_whitespace(cursor);
+    let from: TypeSyntax;
+    if (input[cursor.index] === '(') {
+      cursor.index++; // Skip '('
+      from = parse_type();
+      skip_whitespace(cursor);
+      if (input[cursor.index] !== ')') {
+        return fail(cursor, ["->", ")"], "missing ')'");
+      }
+      cursor.index++; // Skip ')'
+    } else {
+      const name = parse_identifier(cursor);
+      if (!name) {
+        return fail(cursor, ["type variable", "("], cursor.index < input.length ? `unexpected '${input[cursor.index]}'` : "unexpected end of input");
+      }
+      if (!type_vars.includes(name)) {
+        type_vars.push(name);
+      }
+      from = { $: "TVar", id: type_vars.indexOf(name) };
+    }
+    skip_whitespace(cursor);
+    if (input.startsWith("->", cursor.index) || input[cursor.index] === '→') {
+      cursor.index += input[cursor.index] === '→' ? 1 : 2;
+      return { $: "Arrow", from, to: parse_type() };
+    }
+    return from;
+  };
 
-  // Ensure we've consumed all input
-  return index === input.length ? result : null;
+  // Helper function to record where a node was written, from its start to the current offset
+  const located = (term: Term, start: number, end: number = cursor.index): Term => {
+    while (end > start && /\s/.test(input[end - 1])) end--;
+    spans.set(term, { start, end });
+    return term;
+  };
+
+  // Helper function to check if the next character can start an atom
+  // The keyword 'in' ends the value of a let rather than starting an argument
+  const starts_atom = (): boolean => {
+    return cursor.index < input.length && /[λ\\(a-zA-Z_0-9]/.test(input[cursor.index]) && peek_identifier(cursor) !== "in";
+  };
+
+  try {
+    // Parse the entire input
+    const term = parse_term();
+    skip_whitespace(cursor);
+
+    // Ensure we've consumed all input
+    if (cursor.index < input.length) {
+      return fail(cursor, [...ATOM, "end of input"], `unexpected '${input[cursor.index]}'`);
+    }
+    const annotations = {
+      binders: key_by_path(term, binder_types),
+      terms: key_by_path(term, ascribed),
+      names: new Map(type_vars.map((name, id) => [id, name])),
+    };
+    return { $: "Ok", term, free: free_vars, spans: key_by_path(term, spans), annotations };
+  } catch (e) {
+    if (is_parse_error(e)) {
+      return { $: "Err", error: e };
+    }
+    throw e;
+  }
 };
+
+// The largest numeral a number can stand for
+// A numeral n nests n applications, and larger ones exhaust the host stack of
+// the reducers, or its memory when written out
+export const MAX_NUMERAL = 1000;
+
+// The tokens that can start an atom
+const ATOM = ["variable", "number", "λ", "let", "("];
+
+// What starts one more annotated binder after the first: (x :
+const TYPED_BINDER = /^\(\s*[a-zA-Z_][a-zA-Z0-9_']*\s*:/;
+
+// The names reserved for the syntax of local definitions
+const KEYWORDS = ["let", "in"];
@@ -0,0 +1,133 @@
+import { Cursor, ParseContext, Parser, ParseResult } from "./_";
+import { Term } from "../Term/_";
+import { is_parse_error } from "./error";
+import { fail, peek_identifier, skip_whitespace } from "./cursor";
+
+// A parser for terms in raw de Bruijn notation, as printed by show_de_bruijn
+// Accepts multi-digit indices, and names for free variables and definitions,
+// so that parse_de_bruijn(show_de_bruijn(t, { free }), { free, defs }) gives back t
+// - input: the string to be parsed, e.g. λλ1 (1 0)
+// - context.free: the names of the free variables, extended with any unknown name met
+// - context.defs: the names of the definitions, parsed as references
+// = the parsed Term and its free variable names, or a ParseError locating the failure
+export const parse_de_bruijn: Parser = (input: string, context: ParseContext = {}): ParseResult => {
+  const cursor: Cursor = { input, index: 0 };
+  let depth = 0;
+  let free_vars: string[] = [...(context.free ?? [])];
+
+  // Helper function to parse a single term
+  const parse_term = (): Term => {
+    skip_whitespace(cursor);
+
+    let term = parse_atom();
+
+    // Parse applications
+    while (true) {
+      skip_whitespace(cursor);
+      if (!starts_atom()) break;
+      const arg = parse_atom();
+      term = { $: "App", func: term, arg };
+    }
+
+    return term;
+  };
+
+  // Helper function to parse an atom (index, name, lambda, let, or parenthesized term)
+  const parse_atom = (): Term => {
+    skip_whitespace(cursor);
+
+    if (cursor.index >= input.length) {
+      return fail(cursor, ATOM, "unexpected end of input");
+    }
+
+    switch (input[cursor.index]) {
+      case 'λ':
+      case '\\': {
+        // Lambda abstraction: the body follows directly
+        cursor.index++; // Skip 'λ' or '\\'
+        depth++;
+        const body = parse_term();
+        depth--;
+        return { $: "Lam", body };
+      }
+      case '(': {
+        // Parenthesized expression
+        cursor.index++; // Skip '('
+        const term = parse_term();
+        skip_whitespace(cursor);
+        if (input[cursor.index] !== ')') {
+          return fail(cursor, [...ATOM, ")"], "missing ')'");
+        }
+        cursor.index++; // Skip ')'
+        return term;
+      }
+      default: {
+        // Local definition: let value in body
+        if (peek_identifier(cursor) === "let") {
+          cursor.index += "let".length;
+          const value = parse_term();
+          skip_whitespace(cursor);
+          if (peek_identifier(cursor) !== "in") {
+            return fail(cursor, [...ATOM, "in"], "expected 'in' after the value being defined");
+          }
+          cursor.index += "in".length;
+          depth++;
+          const body = parse_term();
+          depth--;
+          return { $: "Let", value, body };
+        }
+
+        // Index
+        const digits = /^[0-9]+/.exec(input.slice(cursor.index));
+        if (digits) {
+          cursor.index += digits[0].length;
+          return { $: "Var", index: parseInt(digits[0], 10) };
+        }
+
+        // Reference to a definition, or named free variable
+        const name = peek_identifier(cursor);
+        if (name === "in") {
+          return fail(cursor, ATOM, "unexpected keyword 'in'");
+        }
+        if (name) {
+          cursor.index += name.length;
+          if (context.defs?.has(name)) {
+            return { $: "Ref", name };
+          }
+          if (!free_vars.includes(name)) free_vars.push(name);
+          return { $: "Var", index: depth + free_vars.indexOf(name) };
+        }
+
+        return fail(cursor, ATOM, `unexpected '${input[cursor.index]}'`);
+      }
+    }
+  };
+
+  // Helper function to check if the next character can start an atom
+  // The keyword 'in' ends the value of a let rather than starting an argument
+  const starts_atom = (): boolean => {
+    return cursor.index < input.length && /[λ\\(0-9a-zA-Z_]/.test(input[cursor.index]) && peek_identifier(cursor) !== "in";
+  };
+
+  try {
+    // Parse the entire input
+    const term = parse_term();
+    skip_whitespace(cursor);
+
+    // Ensure we've consumed all input
+    if (cursor.index < input.length) {
+      return fail(cursor, [...ATOM, "end of input"], `unexpected '${input[cursor.index]}'`);
+    }
+    // Raw de Bruijn notation has no annotations, and is not read back from source spans
+    const annotations = { binders: new Map(), terms: new Map(), names: new Map() };
+    return { $: "Ok", term, free: free_vars, spans: new Map(), annotations };
+  } catch (e) {
+    if (is_parse_error(e)) {
+      return { $: "Err", error: e };
+    }
+    throw e;
+  }
+};
+
+// The tokens that can start an atom
+const ATOM = ["index", "variable", "λ", "let", "("];
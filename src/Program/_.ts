@@ -0,0 +1,17 @@
+import { Term } from "../Term/_";
+import { ParseError } from "../Parser/_";
+import { Annotated } from "../Type/_";
+
+// Represents a program: a sequence of top-level definitions
+// - defs: the closed term of each definition, in source order; a definition
+//   refers to the others through Ref nodes
+// - annotated: each definition as parsed, with its type annotations; unlike
+//   defs, it keeps its references even when the program is inlined
+export type Program = { defs: Map<string, Term>, annotated: Map<string, Annotated> };
+
+// Represents the result of parsing a program
+// - Ok: the parsed Program
+// - Err: why and where parsing failed
+export type ProgramResult
+  = { $: "Ok", program: Program }
+  | { $: "Err", error: ParseError };
@@ -0,0 +1,78 @@
+import { Term } from "../Term/_";
+import { Program } from "./_";
+
+// Replaces every reference to a known definition by the definition itself
+// Definitions are closed, so they can be copied anywhere without shifting.
+// Each definition is inlined once, and its occurrences share the result, so
+// that d2 = d1 d1; d3 = d2 d2; ... takes linear time and memory. Shared nodes,
+// as in definitions inlined before, are likewise only visited once.
+// - term: the term whose references to inline
+// - defs: the definitions, free of cycles
+// - inlined: the definitions inlined so far, by name, extended with new ones
+// - copies: the nodes visited so far and their inlined form, extended with new ones
+// = the term without references to known definitions
+export function inline_refs(term: Term, defs: Map<string, Term>, inlined: Map<string, Term> = new Map(), copies: Map<Term, Term> = new Map()): Term {
+  var copy = copies.get(term);
+  if (!copy) {
+    copy = inline_node(term, defs, inlined, copies);
+    copies.set(term, copy);
+  }
+  return copy;
+}
+
+// Inlines the references of one node, the shared nodes below being visited once
+// A node without references is returned as it is
+// - term: the node
+// - defs: the definitions, free of cycles
+// - inlined: the definitions inlined so far, by name
+// - copies: the nodes visited so far and their inlined form
+// = the node without references to known definitions
+function inline_node(term: Term, defs: Map<string, Term>, inlined: Map<string, Term>, copies: Map<Term, Term>): Term {
+  switch (term.$) {
+    case "Var": {
+      return term;
+    }
+    case "Lam": {
+      var body = inline_refs(term.body, defs, inlined, copies);
+      return body === term.body ? term : { $: "Lam", name: term.name, body };
+    }
+    case "App": {
+      var func = inline_refs(term.func, defs, inlined, copies);
+      var arg  = inline_refs(term.arg, defs, inlined, copies);
+      return func === term.func && arg === term.arg ? term : { $: "App", func, arg };
+    }
+    case "Let": {
+      var value = inline_refs(term.value, defs, inlined, copies);
+      var body  = inline_refs(term.body, defs, inlined, copies);
+      return value === term.value && body === term.body ? term : { $: "Let", name: term.name, value, body };
+    }
+    case "Ref": {
+      var done = inlined.get(term.name);
+      if (done) {
+        return done;
+      }
+      var def = defs.get(term.name);
+      if (!def) {
+        return term;
+      }
+      done = inline_refs(def, defs, inlined, copies);
+      inlined.set(term.name, done);
+      return done;
+    }
+  }
+}
+
+// Inlines all references of a program, leaving self-contained definitions
+// - program: the program to inline
+// - known: the definitions already in scope that the program may refer to
+// = the program where no definition refers to another
+export function inline_program(program: Program, known: Map<string, Term> = new Map()): Program {
+  var scope   = new Map([...known, ...program.defs]);
+  var inlined = new Map<string, Term>();
+  var copies  = new Map<Term, Term>();
+  var defs    = new Map<string, Term>();
+  for (var name of program.defs.keys()) {
+    defs.set(name, inline_refs({ $: "Ref", name }, scope, inlined, copies));
+  }
+  return { defs, annotated: program.annotated };
+}
@@ -0,0 +1,28 @@
+import * as fs from "fs";
+import { Term } from "../Term/_";
+import { ProgramResult } from "./_";
+import { parse_program } from "./parse_program";
+import { inline_program } from "./inline_refs";
+
+// Loads the definitions of a .lam source
+// - source: the program's source
+// - known: the definitions already in scope that the source may refer to
+// - options.inline: whether to inline references, rather than keep them as
+//   constants for the reducer to unfold on demand with the delta rule
+// = the loaded Program, or a ParseError locating the failure
+export function load_source(source: string, known: Map<string, Term> = new Map(), options: { inline?: boolean } = {}): ProgramResult {
+  var result = parse_program(source, known);
+  if (result.$ === "Ok" && options.inline) {
+    return { $: "Ok", program: inline_program(result.program, known) };
+  }
+  return result;
+}
+
+// Loads a .lam source file of definitions
+// - file: the path of the file
+// - known: the definitions already in scope that the file may refer to
+// - options.inline: whether to inline references, rather than keep them as constants
+// = the loaded Program, or a ParseError locating the failure
+export function load_file(file: string, known: Map<string, Term> = new Map(), options: { inline?: boolean } = {}): ProgramResult {
+  return load_source(fs.readFileSync(file, "utf8"), known, options);
+}
@@ -0,0 +1,122 @@
+import { Term } from "../Term/_";
+import { parse } from "../Parser/parse";
+import { parse_error } from "../Parser/error";
+import { ProgramResult } from "./_";
+import { refs } from "../Term/refs";
+import { Annotated } from "../Type/_";
+
+// Parses a program made of definitions such as `id = λx x;`
+// Comments run from `--` to the end of the line. Definitions may refer to
+// each other in any order, and to already known definitions, as long as
+// no definition depends on itself
+// - source: the program's source
+// - known: the definitions already in scope, e.g. from previously loaded files
+// = the parsed Program, or a ParseError locating the failure
+export function parse_program(source: string, known: Map<string, Term> = new Map()): ProgramResult {
+  // Blank out comments, keeping offsets intact
+  var code = source.replace(/--[^\n]*/g, comment => " ".repeat(comment.length));
+
+  // Split the definitions on ';', which never occurs inside a term
+  var chunks: { start: number, text: string }[] = [];
+  var start = 0;
+  for (var index = 0; index < code.length; index++) {
+    if (code[index] === ";") {
+      chunks.push({ start, text: code.slice(start, index) });
+      start = index + 1;
+    }
+  }
+  var rest = code.slice(start);
+  if (rest.trim() !== "") {
+    return err(source, code.length, [";"], "missing ';' after the last definition");
+  }
+
+  // Read the name of every definition first, so that they can refer to each other
+  var heads: { name: string, name_at: number, body: string, body_at: number }[] = [];
+  for (var chunk of chunks) {
+    var head = /^(\s*)([a-zA-Z_][a-zA-Z0-9_']*)(\s*=)/.exec(chunk.text);
+    var at = chunk.start + chunk.text.length - chunk.text.trimStart().length;
+    if (!head) {
+      return err(source, at, ["definition"], "expected a definition of the form 'name = term;'");
+    }
+    if (head[2] === "let" || head[2] === "in") {
+      return err(source, at, ["definition"], `'${head[2]}' is a keyword and cannot be defined`);
+    }
+    if (heads.some(other => other.name === head![2])) {
+      return err(source, at, [], `'${head[2]}' is defined twice`);
+    }
+    heads.push({
+      name: head[2],
+      name_at: at,
+      body: chunk.text.slice(head[0].length),
+      body_at: chunk.start + head[0].length,
+    });
+  }
+
+  // Parse the bodies, which must be closed up to references to definitions
+  var names = new Set([...known.keys(), ...heads.map(head => head.name)]);
+  var defs  = new Map<string, Term>();
+  var annotated = new Map<string, Annotated>();
+  for (var { name, body, body_at } of heads) {
+    var result = parse(body, { defs: names, closed: true });
+    if (result.$ === "Err") {
+      var error = result.error;
+      return err(source, body_at + error.index, error.expected, `${error.message} in the definition of '${name}'`);
+    }
+    defs.set(name, result.term);
+    annotated.set(name, { term: result.term, annotations: result.annotations });
+  }
+
+  // Reject definitions that depend on themselves
+  var cycle = find_cycle(heads.map(head => head.name), new Map([...known, ...defs]));
+  if (cycle) {
+    var culprit = heads.find(head => cycle!.includes(head.name))!;
+    return err(source, culprit.name_at, [], `cyclic definition: ${cycle.join(" -> ")}`);
+  }
+
+  return { $: "Ok", program: { defs, annotated } };
+}
+
+// Builds a failed ProgramResult
+// - source: the program's source
+// - index: the offset of the offending character
+// - expected: the tokens that would have been accepted
+// - message: a human-readable description of the failure
+// = the failed result
+function err(source: string, index: number, expected: string[], message: string): ProgramResult {
+  return { $: "Err", error: parse_error(source, index, expected, message) };
+}
+
+// Looks for a chain of references leading from a definition back to itself
+// - roots: the definitions to start from
+// - defs: all definitions in scope
+// = the cycle, starting and ending at the same name, or null if there is none
+function find_cycle(roots: string[], defs: Map<string, Term>): string[] | null {
+  var acyclic = new Set<string>();
+
+  var visit = (name: string, chain: string[]): string[] | null => {
+    var seen = chain.indexOf(name);
+    if (seen !== -1) {
+      return [...chain.slice(seen), name];
+    }
+    var term = defs.get(name);
+    if (!term || acyclic.has(name)) {
+      return null;
+    }
+    for (var ref of refs(term)) {
+      var cycle = visit(ref, [...chain, name]);
+      if (cycle) {
+        return cycle;
+      }
+    }
+    acyclic.add(name);
+    return null;
+  };
+
+  for (var root of roots) {
+    var cycle = visit(root, []);
+    if (cycle) {
+      return cycle;
+    }
+  }
+  return null;
+}
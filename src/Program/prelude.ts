@@ -0,0 +1,53 @@
+import { ProgramResult } from "./_";
+import { load_source } from "./load";
+
+// The definitions of the standard prelude, in Church encoding
+// Numerals are not defined here: the parser reads 0, 1, 2... as Church numerals
+export const PRELUDE = `
+-- Combinators
+id      = λx x;
+const   = λx y. x;
+
+-- Booleans: a boolean chooses between its two arguments
+true    = λt f. t;
+false   = λt f. f;
+if      = λb t f. b t f;
+not     = λb. b false true;
+and     = λa b. a b false;
+or      = λa b. a true b;
+
+-- Pairs: a pair passes its components to a selector
+pair    = λa b s. s a b;
+fst     = λp. p true;
+snd     = λp. p false;
+
+-- Church numerals: n applies a function n times
+succ    = λn f x. f (n f x);
+add     = λm n f x. m f (n f x);
+mul     = λm n f. m (n f);
+pow     = λm n. n m;
+pred    = λn f x. n (λg h. h (g f)) (λu. x) (λu. u);
+sub     = λm n. n pred m;
+is_zero = λn. n (λx. false) true;
+leq     = λm n. is_zero (sub m n);
+eq      = λm n. and (leq m n) (leq n m);
+
+-- Lists: a list is its own right fold
+nil     = λc n. n;
+cons    = λh t c n. c h (t c n);
+foldr   = λf z l. l f z;
+is_nil  = λl. l (λh t. false) true;
+map     = λf l. foldr (λh t. cons (f h) t) nil l;
+sum     = λl. foldr add 0 l;
+
+-- Fixed points: Y for lazy strategies, Z for call-by-value
+Y       = λf. (λx. f (x x)) (λx. f (x x));
+Z       = λf. (λx. f (λv. x x v)) (λx. f (λv. x x v));
+`;
+
+// Loads the standard prelude, which refers to nothing but itself
+// - options.inline: whether to inline references, rather than keep them as constants
+// = the prelude's definitions
+export function load_prelude(options: { inline?: boolean } = {}): ProgramResult {
+  return load_source(PRELUDE, new Map(), options);
+}
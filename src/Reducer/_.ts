@@ -1,6 +1,94 @@
-import { Term } from "../Term/_";
+import { Path, Term } from "../Term/_";
 
 // Represents a reducer function that takes a Term and performs one step of reduction
 // - term: the Term to be reduced
+// - rules: the optional rules enabled on top of beta
 // = the reduced Term if a reduction was possible, or null if the term is already in normal form
-export type Reducer = (term: Term) => Term | null;
+export type Reducer = (term: Term, rules?: Rules) => Term | null;
+
+// Represents a tracing reducer: like a Reducer, but also reports
+// which redexes were contracted to get to the next term
+// - term: the Term to be reduced
+// - rules: the optional rules enabled on top of beta
+// = the step taken, or null if the term is already in normal form
+export type Tracer = (term: Term, rules?: Rules) => Step | null;
+
+// The optional reduction rules a strategy may apply besides beta
+// - eta: contract λ(M 0) to M, when 0 is not free in M
+// - defs: the definitions that references unfold to (the delta rule)
+export type Rules = {
+  eta?: boolean,
+  defs?: Map<string, Term>,
+};
+
+// The reduction rules a step can apply
+// - beta: (λM) N → M[0 := N]
+// - eta: λ(M 0) → M, when 0 is not free in M
+// - delta: unfolding a definition
+// - zeta: let x = M in N → N[x := M]
+export type Rule = "beta" | "eta" | "delta" | "zeta";
+
+// Represents a single contracted redex
+// - path: where the redex sits in the term being reduced
+// - rule: the rule that contracted it
+// - before: the redex itself
+// - after: what it was replaced with
+export type Redex = { path: Path, rule: Rule, before: Term, after: Term };
+
+// Represents one reduction step
+// - term: the whole term after the step
+// - redexes: the redexes contracted by the step (several for strong reduction)
+export type Step = { term: Term, redexes: Redex[] };
+
+// Names of the available evaluation strategies
+// - call_by_value: weak, leftmost function first, beta only on values
+// - call_by_name: weak, leftmost-outermost, arguments unevaluated
+// - normal_order: strong, leftmost-outermost
+// - applicative_order: strong, leftmost-innermost
+// - head: leftmost-outermost, under lambdas but never in arguments
+// - strong: contracts every redex at once (Gross-Knuth)
+export type Strategy
+  = "call_by_value"
+  | "call_by_name"
+  | "normal_order"
+  | "applicative_order"
+  | "head"
+  | "strong";
+
+// Options for a fuel-limited normalization run
+// - strategy: the strategy (or custom reducer) to use, normal_order by default
+// - fuel: the maximum number of reduction steps, 10000 by default
+// - timeout: the maximum wall-clock time in milliseconds, unlimited by default
+// - detect_cycles: whether to stop when a term repeats, true by default
+// - on_step: called after every step taken
+// - the optional Rules enabled on top of beta
+export type NormalizeOptions = Rules & {
+  strategy?: Strategy | Reducer,
+  fuel?: number,
+  timeout?: number,
+  detect_cycles?: boolean,
+  on_step?: (step: Step) => void,
+};
+
+// Represents the outcome of a normalization run
+// - Normal: no redex is left for the strategy
+// - OutOfFuel: the step budget ran out; term is the last term reached
+// - Timeout: the time limit ran out; term is the last term reached
+// - Cycle: term was already seen period steps before, so the run diverges
+export type Normalization
+  = { $: "Normal", term: Term, steps: number }
+  | { $: "OutOfFuel", term: Term, steps: number }
+  | { $: "Timeout", term: Term, steps: number }
+  | { $: "Cycle", term: Term, steps: number, period: number };
+
+// Describes where a syntax of de Bruijn terms binds variables, so that one
+// shift and one substitute serve both untyped terms and the Calculus of Constructions
+// - index: the index of a node that is a variable, or null for any other node
+// - variable: builds the variable with a given index
+// - map: rebuilds a node with each child replaced, given the number of binders
+//   the node puts around that child; a node without children is returned as is
+export type Binding<T> = {
+  index: (node: T) => number | null,
+  variable: (index: number) => T,
+  map: (node: T, child: (term: T, binders: number) => T) => T,
+};
@@ -0,0 +1,55 @@
+import { Term } from "../Term/_";
+import { Rules, Step } from "./_";
+import { eta_step } from "./eta";
+import { delta_step } from "./delta";
+import { zeta_step } from "./zeta";
+import { beta } from "./substitute";
+import { contract, from_tracer, lift } from "./step";
+
+// Performs one step of applicative order reduction (leftmost-innermost redex first)
+// Both the function and the argument are fully normalized before a redex is contracted
+// - term: the Term to be reduced
+// - rules: the optional rules enabled on top of beta
+// = the step taken, or null if the term is in beta-normal form
+export function applicative_order_step(term: Term, rules: Rules = {}): Step | null {
+  switch (term.$) {
+    case "App": {
+      const { func, arg } = term;
+      var reduced = lift(applicative_order_step(func, rules), "func", func => ({ $: "App", func, arg }))
+                 ?? lift(applicative_order_step(arg, rules), "arg", arg => ({ $: "App", func, arg }));
+      if (reduced) {
+        return reduced;
+      }
+
+      // Both sides are normal, so the application itself is the innermost redex
+      if (func.$ === "Lam") {
+        return contract("beta", term, beta(func.body, arg));
+      }
+      return null;
+    }
+    case "Lam": {
+      const { name } = term;
+      // The body is normalized before the lambda itself may be eta-contracted
+      return lift(applicative_order_step(term.body, rules), "body", body => ({ $: "Lam", name, body }))
+          ?? eta_step(term, rules);
+    }
+    case "Var": {
+      return null;
+    }
+    case "Let": {
+      // Like (λbody) value: the body, then the value are normalized before substituting
+      const { name, value, body } = term;
+      return lift(applicative_order_step(body, rules), "body", body => ({ $: "Let", name, value, body }))
+          ?? lift(applicative_order_step(value, rules), "value", value => ({ $: "Let", name, value, body }))
+          ?? zeta_step(term);
+    }
+    case "Ref": {
+      return delta_step(term, rules);
+    }
+  }
+}
+
+// Performs one step of applicative order reduction
+// - term: the Term to be reduced
+// = the reduced Term, or null if the term is in beta-normal form
+export const applicative_order = from_tracer(applicative_order_step);
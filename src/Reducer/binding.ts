@@ -0,0 +1,18 @@
+import { Term } from "../Term/_";
+import { Binding } from "./_";
+
+// Where untyped terms bind variables: a Lam binds one in its body, a Let one
+// in its body but not in its value, and references to definitions are closed
+export const TERM_BINDING: Binding<Term> = {
+  index: (term) => term.$ === "Var" ? term.index : null,
+  variable: (index) => ({ $: "Var", index }),
+  map: (term, child) => {
+    switch (term.$) {
+      case "Var": return term;
+      case "Lam": return { $: "Lam", name: term.name, body: child(term.body, 1) };
+      case "App": return { $: "App", func: child(term.func, 0), arg: child(term.arg, 0) };
+      case "Let": return { $: "Let", name: term.name, value: child(term.value, 0), body: child(term.body, 1) };
+      case "Ref": return term;
+    }
+  },
+};
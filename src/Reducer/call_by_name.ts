@@ -0,0 +1,42 @@
+import { Term } from "../Term/_";
+import { Rules, Step } from "./_";
+import { eta_step } from "./eta";
+import { delta_step } from "./delta";
+import { lazy_let_step } from "./zeta";
+import { beta } from "./substitute";
+import { contract, from_tracer, lift } from "./step";
+
+// Performs one step of call-by-name reduction
+// Arguments are substituted unevaluated, and lambdas are never entered
+// - term: the Term to be reduced
+// - rules: the optional rules enabled on top of beta
+// = the step taken, or null if the term is in weak head normal form
+export function call_by_name_step(term: Term, rules: Rules = {}): Step | null {
+  switch (term.$) {
+    case "App": {
+      const { func, arg } = term;
+      if (func.$ === "Lam") {
+        return contract("beta", term, beta(func.body, arg));
+      }
+      return lift(call_by_name_step(func, rules), "func", func => ({ $: "App", func, arg }));
+    }
+    case "Lam": {
+      // Lambdas are never entered, but may be eta-contracted as a whole
+      return eta_step(term, rules);
+    }
+    case "Var": {
+      return null;
+    }
+    case "Let": {
+      return lazy_let_step(term, rules, call_by_name_step, false, false);
+    }
+    case "Ref": {
+      return delta_step(term, rules);
+    }
+  }
+}
+
+// Performs one step of call-by-name reduction
+// - term: the Term to be reduced
+// = the reduced Term, or null if the term is in weak head normal form
+export const call_by_name = from_tracer(call_by_name_step);
@@ -0,0 +1,20 @@
+import { Term } from "../Term/_";
+import { Rules, Step } from "./_";
+import { contract } from "./step";
+
+// Unfolds a reference to a definition
+// - term: the term to unfold
+// - rules: the optional rules enabled, holding the definitions
+// = the definition, or null if the term is not a reference to a known definition
+export function delta(term: Term, rules: Rules = {}): Term | null {
+  return term.$ === "Ref" ? rules.defs?.get(term.name) ?? null : null;
+}
+
+// Unfolds the reference at the root of a term, if it names a known definition
+// - term: the term to unfold
+// - rules: the optional rules enabled, holding the definitions
+// = the step taken, or null if there is nothing to unfold
+export function delta_step(term: Term, rules: Rules = {}): Step | null {
+  var after = delta(term, rules);
+  return after ? contract("delta", term, after) : null;
+}
@@ -0,0 +1,40 @@
+import { Term } from "../Term/_";
+import { occurs_free } from "../Term/occurs_free";
+import { Rules, Step } from "./_";
+import { shift } from "./shift";
+import { contract } from "./step";
+
+// Contracts an eta redex λ(M 0) to M, when 0 is not free in M
+// M loses its enclosing binder, so its free variables are shifted down by one
+// - term: the term to contract
+// = the contractum, or null if the term is not an eta redex
+export function eta(term: Term): Term | null {
+  if (term.$ !== "Lam" || term.body.$ !== "App") {
+    return null;
+  }
+  var { func, arg } = term.body;
+  if (arg.$ !== "Var" || arg.index !== 0 || occurs_free(0, func)) {
+    return null;
+  }
+  return shift(func, -1, 0);
+}
+
+// Eta-expands a term M to λ(M 0)
+// M moves under a new binder, so its free variables are shifted up by one
+// - term: the term to expand
+// = the expanded term, equivalent to the original under beta-eta
+export function eta_expand(term: Term): Term {
+  return {
+    $: "Lam",
+    body: { $: "App", func: shift(term, 1, 0), arg: { $: "Var", index: 0 } }
+  };
+}
+
+// Contracts the eta redex at the root of a term, if the eta rule is enabled
+// - term: the term to contract
+// - rules: the optional rules enabled
+// = the step taken, or null if eta is disabled or the term is not an eta redex
+export function eta_step(term: Term, rules: Rules = {}): Step | null {
+  var after = rules.eta ? eta(term) : null;
+  return after ? contract("eta", term, after) : null;
+}
@@ -0,0 +1,43 @@
+import { Term } from "../Term/_";
+import { Rules, Step } from "./_";
+import { eta_step } from "./eta";
+import { delta_step } from "./delta";
+import { lazy_let_step } from "./zeta";
+import { beta } from "./substitute";
+import { contract, from_tracer, lift } from "./step";
+
+// Performs one step of head reduction
+// Like call-by-name, but also enters lambdas, never touching arguments
+// - term: the Term to be reduced
+// - rules: the optional rules enabled on top of beta
+// = the step taken, or null if the term is in head normal form
+export function head_step(term: Term, rules: Rules = {}): Step | null {
+  switch (term.$) {
+    case "App": {
+      const { func, arg } = term;
+      if (func.$ === "Lam") {
+        return contract("beta", term, beta(func.body, arg));
+      }
+      return lift(head_step(func, rules), "func", func => ({ $: "App", func, arg }));
+    }
+    case "Lam": {
+      const { name } = term;
+      return eta_step(term, rules)
+          ?? lift(head_step(term.body, rules), "body", body => ({ $: "Lam", name, body }));
+    }
+    case "Var": {
+      return null;
+    }
+    case "Let": {
+      return lazy_let_step(term, rules, head_step, true, false);
+    }
+    case "Ref": {
+      return delta_step(term, rules);
+    }
+  }
+}
+
+// Performs one step of head reduction
+// - term: the Term to be reduced
+// = the reduced Term, or null if the term is in head normal form
+export const head = from_tracer(head_step);
@@ -0,0 +1,85 @@
+import { Term } from "../Term/_";
+import { equal } from "../Term/equal";
+import { Rules } from "./_";
+import { beta } from "./substitute";
+import { eta } from "./eta";
+import { delta } from "./delta";
+
+// Computes the beta-normal form of a term, reducing under lambdas
+// Follows normal order, so it terminates whenever a normal form exists
+// - term: the Term to be normalized
+// - rules: the optional rules enabled on top of beta
+// = the beta-normal (or beta-eta-normal) form of the term
+export function normal_form(term: Term, rules: Rules = {}): Term {
+  switch (term.$) {
+    case "App": {
+      var func = weak_head_normal_form(term.func, rules);
+      if (func.$ === "Lam") {
+        return normal_form(beta(func.body, term.arg), rules);
+      }
+      return { $: "App", func: normal_form(func, rules), arg: normal_form(term.arg, rules) };
+    }
+    case "Lam": {
+      // Once the body is normal, contracting an eta redex cannot create new redexes
+      var lam: Term = { $: "Lam", name: term.name, body: normal_form(term.body, rules) };
+      return (rules.eta && eta(lam)) || lam;
+    }
+    case "Var": {
+      return term;
+    }
+    case "Let": {
+      return normal_form(beta(term.body, term.value), rules);
+    }
+    case "Ref": {
+      var unfolded = delta(term, rules);
+      return unfolded ? normal_form(unfolded, rules) : term;
+    }
+  }
+}
+
+// Reduces a term until it is a lambda, or an application headed by a
+// variable or by an unknown reference
+// - term: the Term to be reduced
+// - rules: the optional rules enabled on top of beta
+// = the weak head normal form of the term
+function weak_head_normal_form(term: Term, rules: Rules): Term {
+  while (true) {
+    var unfolded = delta(term, rules);
+    if (unfolded) {
+      term = unfolded;
+      continue;
+    }
+    if (term.$ === "Let") {
+      term = beta(term.body, term.value);
+      continue;
+    }
+    if (term.$ !== "App") {
+      return term;
+    }
+    var func = weak_head_normal_form(term.func, rules);
+    if (func.$ !== "Lam") {
+      return { $: "App", func, arg: term.arg };
+    }
+    term = beta(func.body, term.arg);
+  }
+}
+
+// Checks if two terms have the same beta-normal form
+// Diverges if either term has no normal form
+// - a: the first term
+// - b: the second term
+// - rules: the optional rules enabled on top of beta
+// = true if both terms are beta-equivalent
+export function beta_equal(a: Term, b: Term, rules: Rules = {}): boolean {
+  return equal(normal_form(a, rules), normal_form(b, rules));
+}
+
+// Checks if two terms have the same beta-eta-normal form
+// Diverges if either term has no normal form
+// - a: the first term
+// - b: the second term
+// - rules: the optional rules enabled on top of beta and eta
+// = true if both terms are beta-eta-equivalent
+export function beta_eta_equal(a: Term, b: Term, rules: Rules = {}): boolean {
+  return equal(normal_form(a, { ...rules, eta: true }), normal_form(b, { ...rules, eta: true }));
+}
@@ -0,0 +1,48 @@
+import { Term } from "../Term/_";
+import { Rules, Step } from "./_";
+import { eta_step } from "./eta";
+import { delta_step } from "./delta";
+import { lazy_let_step } from "./zeta";
+import { beta } from "./substitute";
+import { contract, from_tracer, lift } from "./step";
+
+// Performs one step of normal order reduction (leftmost-outermost redex first)
+// Reduces under lambdas, and reaches the beta-normal form whenever one exists
+// - term: the Term to be reduced
+// - rules: the optional rules enabled on top of beta
+// = the step taken, or null if the term is in beta-normal form
+export function normal_order_step(term: Term, rules: Rules = {}): Step | null {
+  switch (term.$) {
+    case "App": {
+      const { func, arg } = term;
+      // The outermost redex is the application itself
+      if (func.$ === "Lam") {
+        return contract("beta", term, beta(func.body, arg));
+      }
+
+      // Otherwise, the leftmost redex lives in the function, then in the argument
+      return lift(normal_order_step(func, rules), "func", func => ({ $: "App", func, arg }))
+          ?? lift(normal_order_step(arg, rules), "arg", arg => ({ $: "App", func, arg }));
+    }
+    case "Lam": {
+      const { name } = term;
+      // An eta redex is outermost with respect to anything in its body
+      return eta_step(term, rules)
+          ?? lift(normal_order_step(term.body, rules), "body", body => ({ $: "Lam", name, body }));
+    }
+    case "Var": {
+      return null;
+    }
+    case "Let": {
+      return lazy_let_step(term, rules, normal_order_step, true, true);
+    }
+    case "Ref": {
+      return delta_step(term, rules);
+    }
+  }
+}
+
+// Performs one step of normal order reduction
+// - term: the Term to be reduced
+// = the reduced Term, or null if the term is in beta-normal form
+export const normal_order = from_tracer(normal_order_step);
@@ -0,0 +1,45 @@
+import { Term } from "../Term/_";
+import { show_de_bruijn } from "../Term/show_de_bruijn";
+import { Normalization, NormalizeOptions, Tracer } from "./_";
+import { tracers } from "./strategy";
+import { from_reducer } from "./step";
+
+// Repeatedly reduces a term under a step budget and a time limit
+// Safe on diverging terms: it always returns, reporting why it stopped
+// - term: the Term to be normalized
+// - options: the strategy and the limits of the run
+// = the final term, the number of steps taken, and how the run ended
+export function normalize(term: Term, options: NormalizeOptions = {}): Normalization {
+  var tracer: Tracer = typeof options.strategy === "function"
+    ? from_reducer(options.strategy)
+    : tracers[options.strategy ?? "normal_order"];
+  var fuel     = options.fuel ?? 10000;
+  var deadline = options.timeout !== undefined ? Date.now() + options.timeout : Infinity;
+  var seen: Map<string, number> | null = (options.detect_cycles ?? true) ? new Map() : null;
+
+  var steps = 0;
+  while (true) {
+    if (seen) {
+      var key = show_de_bruijn(term);
+      var last = seen.get(key);
+      if (last !== undefined) {
+        return { $: "Cycle", term, steps, period: steps - last };
+      }
+      seen.set(key, steps);
+    }
+
+    var step = tracer(term, options);
+    if (step === null) {
+      return { $: "Normal", term, steps };
+    }
+    if (steps >= fuel) {
+      return { $: "OutOfFuel", term, steps };
+    }
+    if (Date.now() > deadline) {
+      return { $: "Timeout", term, steps };
+    }
+    options.on_step?.(step);
+    term = step.term;
+    steps++;
+  }
+}
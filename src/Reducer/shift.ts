@@ -0,0 +1,28 @@
+import { Term } from "../Term/_";
+import { Binding } from "./_";
+import { TERM_BINDING } from "./binding";
+
+// Shifts the de Bruijn indices in a term
+// - term: the term to shift
+// - by: the amount to shift by
+// - from: the cutoff index
+// = the shifted term
+export function shift(term: Term, by: number, from: number): Term {
+  return shift_in(TERM_BINDING, term, by, from);
+}
+
+// Shifts the de Bruijn indices in a term of any syntax
+// Indices below the cutoff are bound inside the term, and stay as they are;
+// the cutoff grows by one under each binder
+// - binding: where the syntax binds variables
+// - term: the term to shift
+// - by: the amount to shift by
+// - from: the cutoff index
+// = the shifted term
+export function shift_in<T>(binding: Binding<T>, term: T, by: number, from: number): T {
+  var index = binding.index(term);
+  if (index !== null) {
+    return binding.variable(index < from ? index : index + by);
+  }
+  return binding.map(term, (child, binders) => shift_in(binding, child, by, from + binders));
+}
@@ -0,0 +1,46 @@
+import { Path, Term } from "../Term/_";
+import { Reducer, Rule, Rules, Step, Tracer } from "./_";
+
+// Builds the step that contracts the redex at the root of a term
+// - rule: the rule being applied
+// - before: the redex
+// - after: its contractum
+// = a step whose only redex sits at the root
+export function contract(rule: Rule, before: Term, after: Term): Step {
+  return { term: after, redexes: [{ path: [], rule, before, after }] };
+}
+
+// Lifts a step taken inside a subterm to the enclosing term
+// - step: the step taken by the subterm, or null if it took none
+// - dir: the direction leading from the enclosing term to the subterm
+// - rebuild: rebuilds the enclosing term around the reduced subterm
+// = the step on the enclosing term, or null if the subterm took none
+export function lift(step: Step | null, dir: Path[number], rebuild: (term: Term) => Term): Step | null {
+  if (!step) {
+    return null;
+  }
+  return {
+    term: rebuild(step.term),
+    redexes: step.redexes.map(redex => ({ ...redex, path: [dir, ...redex.path] })),
+  };
+}
+
+// Turns a tracer into a plain reducer that forgets the redexes
+// - tracer: the tracer to wrap
+// = a reducer that returns the term after each step
+export function from_tracer(tracer: Tracer): Reducer {
+  return (term: Term, rules?: Rules): Term | null => {
+    var step = tracer(term, rules);
+    return step ? step.term : null;
+  };
+}
+
+// Turns a plain reducer into a tracer that reports no redexes
+// - reducer: the reducer to wrap
+// = a tracer whose steps carry the next term only
+export function from_reducer(reducer: Reducer): Tracer {
+  return (term: Term, rules?: Rules): Step | null => {
+    var reduced = reducer(term, rules);
+    return reduced ? { term: reduced, redexes: [] } : null;
+  };
+}
@@ -0,0 +1,48 @@
+import { Reducer, Strategy, Tracer } from "./_";
+import { reduce, reduce_step } from "./reduce";
+import { call_by_name, call_by_name_step } from "./call_by_name";
+import { normal_order, normal_order_step } from "./normal_order";
+import { applicative_order, applicative_order_step } from "./applicative_order";
+import { head, head_step } from "./head";
+import { strong, strong_step } from "./strong";
+
+// Maps each strategy name to its one-step reducer
+export const strategies: { [name in Strategy]: Reducer } = {
+  call_by_value: reduce,
+  call_by_name,
+  normal_order,
+  applicative_order,
+  head,
+  strong,
+};
+
+// Maps each strategy name to its tracing reducer
+export const tracers: { [name in Strategy]: Tracer } = {
+  call_by_value: reduce_step,
+  call_by_name: call_by_name_step,
+  normal_order: normal_order_step,
+  applicative_order: applicative_order_step,
+  head: head_step,
+  strong: strong_step,
+};
+
+// Checks if a string names an evaluation strategy
+// - name: the candidate strategy name
+// = true if the name is a known Strategy
+export function is_strategy(name: string): name is Strategy {
+  return Object.prototype.hasOwnProperty.call(strategies, name);
+}
+
+// Looks up a reducer by strategy name
+// - name: the name of the strategy
+// = the corresponding Reducer, or null if no such strategy exists
+export function get_reducer(name: string): Reducer | null {
+  return is_strategy(name) ? strategies[name] : null;
+}
+
+// Looks up a tracing reducer by strategy name
+// - name: the name of the strategy
+// = the corresponding Tracer, or null if no such strategy exists
+export function get_tracer(name: string): Tracer | null {
+  return is_strategy(name) ? tracers[name] : null;
+}
@@ -0,0 +1,107 @@
+import { Path, Term } from "../Term/_";
+import { Redex, Rules, Step } from "./_";
+import { beta } from "./substitute";
+import { eta } from "./eta";
+import { delta } from "./delta";
+import { shift } from "./shift";
+import { from_tracer } from "./step";
+
+// Performs one step of Gross-Knuth reduction: every redex of the term,
+// including those under lambdas, is contracted at once (a complete development)
+// This strategy is normalizing and usually needs fewer steps than normal order
+// - term: the Term to be reduced
+// - rules: the optional rules enabled on top of beta
+// = the step taken, listing the outermost contracted redexes, or null if the term is in normal form
+export function strong_step(term: Term, rules: Rules = {}): Step | null {
+  if (!has_redex(term, rules)) {
+    return null;
+  }
+  var redexes: Redex[] = [];
+  return { term: develop(term, [], redexes, rules), redexes };
+}
+
+// Performs one step of Gross-Knuth reduction
+// - term: the Term to be reduced
+// = the reduced Term, or null if the term is in normal form
+export const strong = from_tracer(strong_step);
+
+// Contracts all redexes of a term, innermost first
+// - term: the term to develop
+// - path: the path to the term, or null inside a redex already being recorded
+// - redexes: collects the outermost redexes contracted
+// - rules: the optional rules enabled on top of beta
+// = the complete development of the term
+function develop(term: Term, path: Path | null, redexes: Redex[], rules: Rules): Term {
+  switch (term.$) {
+    case "App": {
+      if (term.func.$ === "Lam") {
+        const after = beta(develop(term.func.body, null, redexes, rules), develop(term.arg, null, redexes, rules));
+        if (path) {
+          redexes.push({ path, rule: "beta", before: term, after });
+        }
+        return after;
+      }
+      return {
+        $: "App",
+        func: develop(term.func, path && [...path, "func"], redexes, rules),
+        arg: develop(term.arg, path && [...path, "arg"], redexes, rules)
+      };
+    }
+    case "Lam": {
+      if (rules.eta && eta(term) && term.body.$ === "App") {
+        // Developing M keeps 0 out of its free variables, so the eta redex
+        // λ(M 0) develops to the development of M, one binder up
+        const after = shift(develop(term.body.func, null, redexes, rules), -1, 0);
+        if (path) {
+          redexes.push({ path, rule: "eta", before: term, after });
+        }
+        return after;
+      }
+      return { $: "Lam", name: term.name, body: develop(term.body, path && [...path, "body"], redexes, rules) };
+    }
+    case "Var": {
+      return term;
+    }
+    case "Let": {
+      const after = beta(develop(term.body, null, redexes, rules), develop(term.value, null, redexes, rules));
+      if (path) {
+        redexes.push({ path, rule: "zeta", before: term, after });
+      }
+      return after;
+    }
+    case "Ref": {
+      const after = delta(term, rules);
+      if (!after) {
+        return term;
+      }
+      if (path) {
+        redexes.push({ path, rule: "delta", before: term, after });
+      }
+      return after;
+    }
+  }
+}
+
+// Checks if a term contains a redex anywhere
+// - term: the term to check
+// - rules: the optional rules enabled on top of beta
+// = true if some subterm is of the form (λM) N, a let, a known reference, or an eta redex when enabled
+function has_redex(term: Term, rules: Rules): boolean {
+  switch (term.$) {
+    case "App": {
+      return term.func.$ === "Lam" || has_redex(term.func, rules) || has_redex(term.arg, rules);
+    }
+    case "Lam": {
+      return (rules.eta === true && eta(term) !== null) || has_redex(term.body, rules);
+    }
+    case "Var": {
+      return false;
+    }
+    case "Let": {
+      return true;
+    }
+    case "Ref": {
+      return delta(term, rules) !== null;
+    }
+  }
+}
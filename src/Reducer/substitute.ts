@@ -0,0 +1,47 @@
+import { Term } from "../Term/_";
+import { Binding } from "./_";
+import { TERM_BINDING } from "./binding";
+import { shift_in } from "./shift";
+
+// Substitutes a term for a variable in another term
+// - term: the term to perform substitution in
+// - index: the de Bruijn index to substitute for
+// - replacement: the term to substitute
+// = the term after substitution
+export function substitute(term: Term, index: number, replacement: Term): Term {
+  return substitute_in(TERM_BINDING, term, index, replacement);
+}
+
+// Substitutes a term for a variable in another term of any syntax
+// Under each binder, the index and the free variables of the replacement
+// are one level deeper; the variables above the index are lowered by one
+// - binding: where the syntax binds variables
+// - term: the term to perform substitution in
+// - index: the de Bruijn index to substitute for
+// - replacement: the term to substitute
+// = the term after substitution
+export function substitute_in<T>(binding: Binding<T>, term: T, index: number, replacement: T): T {
+  var found = binding.index(term);
+  if (found !== null) {
+    if (found === index) {
+      return replacement;
+    } else if (found > index) {
+      return binding.variable(found - 1);
+    } else {
+      return term;
+    }
+  }
+  return binding.map(term, (child, binders) =>
+    substitute_in(binding, child, index + binders, binders === 0 ? replacement : shift_in(binding, replacement, binders, 0)));
+}
+
+// Contracts a beta redex (λbody) arg
+// The argument lives outside the removed binder, so it is substituted as is:
+// substitute shifts it once per lambda it crosses, and lowers the free
+// variables of the body by one
+// - body: the body of the applied lambda
+// - arg: the argument it is applied to
+// = the contractum
+export function beta(body: Term, arg: Term): Term {
+  return substitute(body, 0, arg);
+}
@@ -0,0 +1,20 @@
+import { Term } from "../Term/_";
+import { Normalization, NormalizeOptions, Step } from "./_";
+import { normalize } from "./normalize";
+
+// Normalizes a term while recording every step taken
+// - term: the Term to be normalized
+// - options: the strategy and the limits of the run
+// = the steps taken, in order, and how the run ended
+export function trace(term: Term, options: NormalizeOptions = {}): { steps: Step[], result: Normalization } {
+  var steps: Step[] = [];
+  var on_step = options.on_step;
+  var result = normalize(term, {
+    ...options,
+    on_step: step => {
+      steps.push(step);
+      on_step?.(step);
+    },
+  });
+  return { steps, result };
+}
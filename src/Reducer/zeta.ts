@@ -0,0 +1,64 @@
+import { Term } from "../Term/_";
+import { occurs_free } from "../Term/occurs_free";
+import { Rules, Step, Tracer } from "./_";
+import { beta } from "./substitute";
+import { contract, lift } from "./step";
+
+// Contracts a local definition: let x = M in N → N[x := M]
+// - term: the let to contract
+// = the step taken
+export function zeta_step(term: Extract<Term, { $: "Let" }>): Step {
+  return contract("zeta", term, beta(term.body, term.value));
+}
+
+// Performs one step on a local definition under a lazy strategy
+// The bound value is only reduced once its variable is needed, and is then
+// reduced in place, so that its work is shared by every occurrence: it is
+// substituted only once it is a value, or when the body no longer uses it.
+// When the body is stuck with the variable out of head position, a strategy
+// that reduces arguments would reduce every copy, so the value is reduced
+// in place first; other strategies substitute it as it is, never reducing it.
+// - term: the let to reduce
+// - rules: the optional rules enabled on top of beta
+// - tracer: the lazy strategy being followed
+// - under_lambdas: whether the strategy reduces under lambdas
+// - reduces_args: whether the strategy reduces the arguments of stuck applications
+// = the step taken
+export function lazy_let_step(term: Extract<Term, { $: "Let" }>, rules: Rules, tracer: Tracer, under_lambdas: boolean, reduces_args: boolean): Step {
+  const { name, value, body } = term;
+  if (value.$ === "Lam" || value.$ === "Var" || !occurs_free(0, body)) {
+    return zeta_step(term);
+  }
+  const in_value = (): Step | null => lift(tracer(value, rules), "value", value => ({ $: "Let", name, value, body }));
+  if (needs(body, 0, under_lambdas)) {
+    return in_value() ?? zeta_step(term);
+  }
+  return lift(tracer(body, rules), "body", body => ({ $: "Let", name, value, body }))
+      ?? (reduces_args ? in_value() : null)
+      ?? zeta_step(term);
+}
+
+// Checks if a variable is in head position, i.e. its value is needed for the term to make progress
+// - term: the term to inspect
+// - index: the de Bruijn index of the variable, relative to the term
+// - under_lambdas: whether to look under lambdas
+// = true if the variable heads the term
+function needs(term: Term, index: number, under_lambdas: boolean): boolean {
+  switch (term.$) {
+    case "Var": {
+      return term.index === index;
+    }
+    case "Lam": {
+      return under_lambdas && needs(term.body, index + 1, under_lambdas);
+    }
+    case "App": {
+      return needs(term.func, index, under_lambdas);
+    }
+    case "Let": {
+      return needs(term.body, index + 1, under_lambdas);
+    }
+    case "Ref": {
+      return false;
+    }
+  }
+}
@@ -0,0 +1,34 @@
+import { Term } from "../Term/_";
+import { Strategy } from "../Reducer/_";
+import { Annotated } from "../Type/_";
+
+// Represents a term together with the names of its free variables
+// - term: the term
+// - free: the names of its free variables
+export type Open = { term: Term, free: string[] };
+
+// Represents the state of a REPL session
+// - strategy: the strategy used by :step, :norm and :trace
+// - eta: whether eta reduction is enabled
+// - delta: whether definitions are kept as references unfolded on demand,
+//   rather than inlined as soon as a term is read
+// - fuel: the step budget of :norm and :trace
+// - defs: the top-level definitions, by name
+// - annotated: the top-level definitions as parsed, with their type annotations
+// - current: the term being stepped through, if any
+export type Session = {
+  strategy: Strategy,
+  eta: boolean,
+  delta: boolean,
+  fuel: number,
+  defs: Map<string, Term>,
+  annotated: Map<string, Annotated>,
+  current: Open | null,
+};
+
+// Represents what the REPL should do after a line
+// - Print: print the given text and keep going
+// - Quit: end the session
+export type Reply
+  = { $: "Print", text: string }
+  | { $: "Quit" };
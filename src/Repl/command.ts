@@ -0,0 +1,669 @@
+import * as fs from "fs";
+import { Open, Reply, Session } from "./_";
+import { Program } from "../Program/_";
+import { parse } from "../Parser/parse";
+import { show_parse_error, show_span } from "../Parser/error";
+import { show_de_bruijn } from "../Term/show_de_bruijn";
+import { show_named } from "../Term/show_named";
+import { desugar_let } from "../Term/desugar_let";
+import { decode } from "../Data/decode";
+import { show_data } from "../Data/show_data";
+import { Normalization, Rules } from "../Reducer/_";
+import { normalize } from "../Reducer/normalize";
+import { trace } from "../Reducer/trace";
+import { is_strategy, strategies, tracers } from "../Reducer/strategy";
+import { load_source } from "../Program/load";
+import { inline_refs } from "../Program/inline_refs";
+import { load_prelude } from "../Program/prelude";
+import { compare_sharing } from "../Machine/compare_sharing";
+import { cross_check } from "../Machine/cross_check";
+import { compare_optimal } from "../Net/optimal";
+import { Interactions } from "../Net/_";
+import { Basis } from "../Combinator/_";
+import { compile_comb } from "../Combinator/compile_comb";
+import { reduce_comb } from "../Combinator/reduce_comb";
+import { comb_to_term } from "../Combinator/comb_to_term";
+import { show_comb } from "../Combinator/show_comb";
+import { comb_size } from "../Combinator/comb_size";
+import { size } from "../Term/size";
+import { refs } from "../Term/refs";
+import { equal } from "../Term/equal";
+import { parse_f } from "../SystemF/parse_f";
+import { check_f } from "../SystemF/check_f";
+import { erase_f } from "../SystemF/erase_f";
+import { show_ftype } from "../SystemF/show_ftype";
+import { parse_core } from "../Core/parse_core";
+import { check_core } from "../Core/check_core";
+import { normalize_core } from "../Core/normalize_core";
+import { show_core } from "../Core/show_core";
+import { infer_scheme } from "../Type/infer";
+import { check } from "../Type/check";
+import { path_key } from "../Term/path_key";
+import { infer_defs } from "../Type/infer_defs";
+import { show_scheme, show_type } from "../Type/show_type";
+
+// The commands understood by the REPL, with their help text
+const HELP = [
+  "<term>              normalize a term, e.g. (λx x) y or (\\x. x) y",
+  "<name> = <term>     define a name, usable in later terms",
+  ":load <file>        load the definitions of a .lam file",
+  ":prelude            load the standard prelude: booleans, pairs, numerals, lists, Y and Z",
+  ":step [<term>]      take one step on the given term, or on the last one",
+  ":norm [<term>]      normalize the given term, or the last one",
+  ":trace [<term>]     normalize, showing every step and its redexes",
+  ":desugar [<term>]   rewrite every let x = M in N into (λx N) M",
+  ":need [<term>]      evaluate with call-by-need, counting the beta steps saved",
+  ":nbe [<term>]       normalize by evaluation, checking the result against normal order",
+  ":optimal [<term>]   normalize by optimal reduction, counting the interactions",
+  ":ski [<term>]       compile to SKI and BCKW combinators, and run them",
+  ":systemf <term>     type check a System F term, then erase and normalize it",
+  ":core <term>        type check a term of the Calculus of Constructions",
+  ":strategy [<name>]  show or set the evaluation strategy",
+  ":eta [on|off]       show or set eta reduction",
+  ":delta [on|off]     keep definitions as constants (on) or inline them (off)",
+  ":fuel [<steps>]     show or set the step budget",
+  ":type <term>        infer the type scheme of a term, with polymorphic let",
+  ":check <term>       check a term against its annotations, (M : T) and λ(x : T) M",
+  ":types              list the type scheme of every definition",
+  ":defs               list the definitions",
+  ":help               show this help",
+  ":quit               leave the REPL",
+].join("\n");
+
+// Creates a fresh REPL session
+// = a session with normal order reduction and no definitions
+export function new_session(): Session {
+  return { strategy: "normal_order", eta: false, delta: true, fuel: 10000, defs: new Map(), annotated: new Map(), current: null };
+}
+
+// Runs one line of REPL input
+// - session: the session state, updated in place
+// - line: the line typed by the user
+// = what to print, or whether to quit
+export function run_line(session: Session, line: string): Reply {
+  var input = line.trim();
+  if (input === "") {
+    return print("");
+  }
+  if (!input.startsWith(":")) {
+    return /^[a-zA-Z_][a-zA-Z0-9_']*\s*=/.test(input) ? define(session, input) : norm(session, input);
+  }
+
+  var space   = input.search(/\s/);
+  var command = space === -1 ? input : input.slice(0, space);
+  var arg     = space === -1 ? "" : input.slice(space).trim();
+  switch (command) {
+    case ":step":     return step(session, arg);
+    case ":norm":     return norm(session, arg);
+    case ":trace":    return show_trace(session, arg);
+    case ":desugar":  return desugar(session, arg);
+    case ":need":     return need(session, arg);
+    case ":nbe":      return show_nbe(session, arg);
+    case ":optimal":  return show_optimal(session, arg);
+    case ":ski":      return show_combinators(session, arg);
+    case ":systemf":  return show_system_f(session, arg);
+    case ":core":     return show_core_check(arg);
+    case ":strategy": return set_strategy(session, arg);
+    case ":load":     return load(session, arg);
+    case ":prelude":  return prelude(session);
+    case ":eta":      return set_eta(session, arg);
+    case ":delta":    return set_delta(session, arg);
+    case ":fuel":     return set_fuel(session, arg);
+    case ":type":     return show_type_of(session, arg);
+    case ":types":    return show_def_types(session);
+    case ":check":    return show_check(session, arg);
+    case ":defs":     return show_defs(session);
+    case ":help":     return print(HELP);
+    case ":quit":     return { $: "Quit" };
+    default:          return print(`Unknown command ${command}, try :help`);
+  }
+}
+
+// Wraps some text into a Print reply
+// - text: the text to print
+// = the reply
+function print(text: string): Reply {
+  return { $: "Print", text };
+}
+
+// Parses a term typed in the REPL, where defined names become references
+// - session: the session state
+// - input: the term's source
+// = the term, or the rendered parse error
+function read_term(session: Session, input: string): Open | string {
+  var result = parse(input, { defs: new Set(session.defs.keys()) });
+  if (result.$ === "Err") {
+    return show_parse_error(input, result.error);
+  }
+  var term = session.delta ? result.term : inline_refs(result.term, session.defs);
+  return { term, free: result.free };
+}
+
+// The rules to reduce with in a session
+// - session: the session state
+// = the enabled rules
+function rules(session: Session): Rules {
+  return { eta: session.eta, defs: session.defs };
+}
+
+// Picks the term a command works on: the argument if any, else the current term
+// - session: the session state, whose current term is updated
+// - arg: the command's argument
+// = the term, or an error message
+function target(session: Session, arg: string): Open | string {
+  if (arg === "") {
+    return session.current ?? "No current term: type a term first.";
+  }
+  var open = read_term(session, arg);
+  if (typeof open !== "string") {
+    session.current = open;
+  }
+  return open;
+}
+
+// Shows a term in both de Bruijn and named notation
+// - open: the term and its free variable names
+// = the two renderings on one line
+function show(open: Open): string {
+  return `${show_de_bruijn(open.term, { free: open.free })}   ~   ${show_named(open.term, { free: open.free })}`;
+}
+
+// Shows a normal form, followed by every reading of it as data
+// - open: the normal form and its free variable names
+// = the renderings and readings on one line
+function show_normal(open: Open): string {
+  var readings = decode(open.term).map(data => show_data(data, open.free));
+  return readings.length > 0 ? `${show(open)}   =   ${readings.join(" | ")}` : show(open);
+}
+
+// Describes how a normalization run ended
+// - result: the outcome of the run
+// = a one-line summary of the outcome
+function show_outcome(result: Normalization): string {
+  switch (result.$) {
+    case "Normal":    return `normal form after ${result.steps} steps`;
+    case "OutOfFuel": return `out of fuel after ${result.steps} steps`;
+    case "Timeout":   return `timed out after ${result.steps} steps`;
+    case "Cycle":     return `diverges: the term repeats every ${result.period} steps`;
+  }
+}
+
+// Binds names to closed terms, as in a .lam file
+// - session: the session state
+// - source: one or more definitions, the final ';' being optional
+// = a confirmation, or the parse error
+function define(session: Session, source: string): Reply {
+  var program = source.trimEnd().endsWith(";") ? source : `${source};`;
+  var result  = load_source(program, session.defs, { inline: !session.delta });
+  if (result.$ === "Err") {
+    return print(show_parse_error(program, result.error));
+  }
+  return add_defs(session, result.program);
+}
+
+// Loads the definitions of a .lam file
+// - session: the session state
+// - file: the path of the file
+// = the names loaded, or the error
+function load(session: Session, file: string): Reply {
+  if (file === "") {
+    return print("Usage: :load <file>");
+  }
+  var source: string;
+  try {
+    source = fs.readFileSync(file, "utf8");
+  } catch (e) {
+    return print(`Cannot read ${file}: ${(e as Error).message}`);
+  }
+  var result = load_source(source, session.defs, { inline: !session.delta });
+  if (result.$ === "Err") {
+    return print(show_parse_error(source, result.error));
+  }
+  return add_defs(session, result.program);
+}
+
+// Loads the standard prelude of Church-encoded booleans, pairs, numerals and lists
+// - session: the session state
+// = one line per definition
+function prelude(session: Session): Reply {
+  var result = load_prelude({ inline: !session.delta });
+  if (result.$ === "Err") {
+    return print(`The prelude does not parse: ${result.error.message}`);
+  }
+  return add_defs(session, result.program);
+}
+
+// Adds definitions to the session
+// Each definition is shown as written, since its inlined form can be
+// exponentially larger. With the delta rule, definitions refer to each other
+// by name, so a name that others refer to cannot be given a new meaning.
+// - session: the session state
+// - program: the new definitions
+// = one line per definition, or why they are rejected
+function add_defs(session: Session, program: Program): Reply {
+  if (session.delta) {
+    for (var [name, term] of program.defs) {
+      var old = session.defs.get(name);
+      var user = [...session.defs].find(([other, def]) => !program.defs.has(other) && refs(def).has(name));
+      if (old && user && !equal(old, term)) {
+        return print(`Cannot redefine ${name}: ${user[0]} refers to it`);
+      }
+    }
+  }
+  var lines: string[] = [];
+  for (var [name, term] of program.defs) {
+    var source = program.annotated.get(name)!;
+    session.defs.set(name, term);
+    session.annotated.set(name, source);
+    lines.push(`${name} = ${show_de_bruijn(source.term)}   ~   ${show_named(source.term)}`);
+  }
+  return print(lines.join("\n"));
+}
+
+// Takes one reduction step on a term
+// - session: the session state
+// - arg: the term's source, or "" for the current term
+// = the step, with the contracted redexes highlighted
+function step(session: Session, arg: string): Reply {
+  var open = target(session, arg);
+  if (typeof open === "string") {
+    return print(open);
+  }
+  var taken = tracers[session.strategy](open.term, rules(session));
+  if (!taken) {
+    return print(`${show(open)}\nNo redex left for ${session.strategy}.`);
+  }
+  var highlight = taken.redexes.map(redex => redex.path);
+  var applied   = taken.redexes.map(redex => redex.rule).join(", ");
+  session.current = { term: taken.term, free: open.free };
+  return print([
+    `${show_de_bruijn(open.term, { free: open.free, highlight })}   (${applied})`,
+    show(session.current),
+  ].join("\n"));
+}
+
+// Normalizes a term with the session's strategy and budget
+// - session: the session state
+// - arg: the term's source, or "" for the current term
+// = the final term and how the run ended
+function norm(session: Session, arg: string): Reply {
+  var open = target(session, arg);
+  if (typeof open === "string") {
+    return print(open);
+  }
+  var result = normalize(open.term, { ...rules(session), strategy: session.strategy, fuel: session.fuel });
+  session.current = { term: result.term, free: open.free };
+  var shown = result.$ === "Normal" ? show_normal(session.current) : show(session.current);
+  return print(`${shown}\n(${show_outcome(result)})`);
+}
+
+// Normalizes a term, showing every step
+// - session: the session state
+// - arg: the term's source, or "" for the current term
+// = one line per step, with the contracted redexes highlighted
+function show_trace(session: Session, arg: string): Reply {
+  var open = target(session, arg);
+  if (typeof open === "string") {
+    return print(open);
+  }
+  var free = open.free;
+  var { steps, result } = trace(open.term, { ...rules(session), strategy: session.strategy, fuel: session.fuel });
+  var lines: string[] = [];
+  var term = open.term;
+  for (var taken of steps) {
+    var highlight = taken.redexes.map(redex => redex.path);
+    var applied   = taken.redexes.map(redex => redex.rule).join(", ");
+    lines.push(`${show_de_bruijn(term, { free, highlight })}   (${applied})`);
+    term = taken.term;
+  }
+  session.current = { term: result.term, free };
+  lines.push(show(session.current));
+  lines.push(`(${show_outcome(result)})`);
+  return print(lines.join("\n"));
+}
+
+// Evaluates a term with call-by-need, comparing its beta steps with call-by-name
+// - session: the session state
+// - arg: the term's source, or "" for the current term
+// = the normal form and the beta steps taken by both evaluators
+function need(session: Session, arg: string): Reply {
+  var open = target(session, arg);
+  if (typeof open === "string") {
+    return print(open);
+  }
+  var { need, name, name_beta, saved } = compare_sharing(open.term, { defs: session.defs, fuel: session.fuel });
+  var lines: string[] = [];
+  if (need.$ === "Done") {
+    session.current = { term: need.term, free: open.free };
+    lines.push(show(session.current));
+    lines.push(`call-by-need: ${need.counts.beta} beta steps`);
+  } else if (need.$ === "OutOfFuel") {
+    lines.push(`call-by-need: out of fuel after ${need.counts.beta} beta steps`);
+  } else {
+    lines.push(`call-by-need: out of host stack after ${need.counts.beta} beta steps (more fuel will not help)`);
+  }
+  lines.push(name.$ === "Normal"
+    ? `call-by-name: ${name_beta} beta steps`
+    : `call-by-name: ${name_beta} beta steps, then ${show_outcome(name)}`);
+  if (saved !== null) {
+    lines.push(`saved by sharing: ${saved} beta steps`);
+  }
+  return print(lines.join("\n"));
+}
+
+// Normalizes a term by evaluation, and cross-checks it with normal order reduction
+// - session: the session state
+// - arg: the term's source, or "" for the current term
+// = the normal form and the outcome of the check
+function show_nbe(session: Session, arg: string): Reply {
+  var open = target(session, arg);
+  if (typeof open === "string") {
+    return print(open);
+  }
+  var free = open.free;
+  var check = cross_check(open.term, { defs: session.defs, fuel: session.fuel });
+  switch (check.$) {
+    case "Agree": {
+      session.current = { term: check.term, free };
+      return print(`${show(session.current)}\n(normal order agrees)`);
+    }
+    case "Disagree": {
+      session.current = { term: check.nbe, free };
+      return print(`${show(session.current)}\n(normal order disagrees: ${show({ term: check.reducer, free })})`);
+    }
+    case "StackOverflow": {
+      var overflow = `Normalization by evaluation ran out of host stack after ${check.beta} beta steps; more fuel will not help.`;
+      if (!check.reducer) {
+        return print(`${overflow}\nNormal order found no normal form within ${session.fuel} steps.`);
+      }
+      session.current = { term: check.reducer, free };
+      return print(`${show(session.current)}\n(found by normal order only: ${overflow})`);
+    }
+    case "Unknown": {
+      return print(`No normal form found within ${session.fuel} steps.`);
+    }
+  }
+}
+
+// Normalizes a term by optimal reduction, comparing its interactions with the beta steps of normal order
+// - session: the session state
+// - arg: the term's source, or "" for the current term
+// = the normal form, the interactions and the beta steps
+function show_optimal(session: Session, arg: string): Reply {
+  var open = target(session, arg);
+  if (typeof open === "string") {
+    return print(open);
+  }
+  var { optimal, beta_steps, agree } = compare_optimal(open.term, { defs: session.defs, fuel: session.fuel });
+  var lines: string[] = [];
+  var unsupported = false;
+  switch (optimal.$) {
+    case "Done": {
+      if (agree === false) {
+        // A wrong result is not made the current term
+        lines.push(`optimal reduction: read back the wrong normal form ${show({ term: optimal.term, free: open.free })}`);
+        unsupported = true;
+      } else {
+        session.current = { term: optimal.term, free: open.free };
+        lines.push(show(session.current));
+      }
+      lines.push(`optimal reduction: ${show_interactions(optimal.interactions)}`);
+      break;
+    }
+    case "OutOfFuel": {
+      lines.push(`optimal reduction: out of fuel after ${show_interactions(optimal.interactions)}`);
+      break;
+    }
+    case "Unreadable": {
+      lines.push(`optimal reduction: no term can be read back after ${show_interactions(optimal.interactions)}`);
+      unsupported = true;
+      break;
+    }
+    case "StackOverflow": {
+      lines.push(`optimal reduction: reading back ran out of host stack after ${show_interactions(optimal.interactions)}`);
+      unsupported = true;
+      break;
+    }
+  }
+  lines.push(beta_steps === null
+    ? `normal order: out of fuel after ${session.fuel} steps`
+    : `normal order: ${beta_steps} beta steps`);
+  if (unsupported) {
+    lines.push("(optimal reduction does not support terms outside elementary affine logic, such as self-application λx. x x)");
+  }
+  return print(lines.join("\n"));
+}
+
+// Describes the interactions of an optimal reduction
+// - interactions: the counters of the reduction
+// = a one-line summary
+function show_interactions(interactions: Interactions): string {
+  var { total, beta, annihilate, commute, erase } = interactions;
+  return `${total} interactions (${beta} beta, ${annihilate} annihilate, ${commute} commute, ${erase} erase)`;
+}
+
+// Infers the type scheme of a term, without making it the current term
+// - session: the session state
+// - arg: the term's source
+// = the term and its principal type scheme, or the subterm that cannot be typed
+function show_type_of(session: Session, arg: string): Reply {
+  if (arg === "") {
+    return print("Usage: :type <term>");
+  }
+  var open = read_term(session, arg);
+  if (typeof open === "string") {
+    return print(open);
+  }
+  var free = open.free;
+  var inferred = infer_scheme(open.term, session.defs);
+  if (inferred.$ === "Err") {
+    var highlight = [inferred.error.path];
+    return print(`${show_de_bruijn(open.term, { free, highlight })}\nType error: ${inferred.error.message}`);
+  }
+  var names = new Map<number, string>();
+  var context = inferred.free
+    .map((type, index) => `${free[index]} : ${show_type(type, names)}`);
+  var judgement = `${show_named(open.term, { free })} : ${show_scheme(inferred.scheme, names)}`;
+  return print(context.length > 0 ? `${context.join(", ")} ⊢ ${judgement}` : judgement);
+}
+
+// Checks a term against its type annotations, without making it the current term
+// - session: the session state
+// - arg: the term's source
+// = the term and its type, or the source of the subterm that is not well typed
+function show_check(session: Session, arg: string): Reply {
+  if (arg === "") {
+    return print("Usage: :check <term>, e.g. :check λ(f : a -> b) (x : a). f x");
+  }
+  var parsed = parse(arg, { defs: new Set(session.defs.keys()) });
+  if (parsed.$ === "Err") {
+    return print(show_parse_error(arg, parsed.error));
+  }
+  var checked = check(parsed.term, parsed.annotations, session.annotated);
+  if (checked.$ === "Err") {
+    var span = parsed.spans.get(path_key(checked.error.path)) ?? parsed.spans.get("");
+    return print(span ? show_span(arg, span, "Type error", checked.error.message) : `Type error: ${checked.error.message}`);
+  }
+  var type = show_type(checked.type, new Map(parsed.annotations.names));
+  return print(`${show_named(parsed.term, { free: parsed.free })} : ${type}`);
+}
+
+// Lists the type scheme of every definition
+// - session: the session state
+// = one line per definition
+function show_def_types(session: Session): Reply {
+  if (session.defs.size === 0) {
+    return print("No definitions.");
+  }
+  var lines: string[] = [];
+  for (var [name, inferred] of infer_defs(session.defs)) {
+    lines.push(inferred.$ === "Ok"
+      ? `${name} : ${show_scheme(inferred.scheme)}`
+      : `${name} : not typable, ${inferred.error.message}`);
+  }
+  return print(lines.join("\n"));
+}
+
+// Compiles a term to combinators with both bases, reduces them, and translates the result back
+// - session: the session state
+// - arg: the term's source, or "" for the current term
+// = the combinator expressions, their sizes and reductions, and the normal form read back
+function show_combinators(session: Session, arg: string): Reply {
+  var open = target(session, arg);
+  if (typeof open === "string") {
+    return print(open);
+  }
+  var free = open.free;
+  var term = inline_refs(open.term, session.defs);
+  var lines = [`term size ${size(term)}`];
+  // Turner's optimizations eta-reduce, so normal forms are compared up to eta
+  var expected = normalize(term, { strategy: "normal_order", fuel: session.fuel, eta: true });
+  for (var basis of ["SKI", "BCKW"] as Basis[]) {
+    var comb = compile_comb(term, basis);
+    var reduced = reduce_comb(comb, session.fuel);
+    lines.push(`${basis.padEnd(4)} size ${comb_size(comb)}: ${show_comb(comb, free)}`);
+    if (reduced.$ === "OutOfFuel") {
+      lines.push(`     out of fuel after ${reduced.steps} steps`);
+      continue;
+    }
+    lines.push(`     ${reduced.steps} steps to ${show_comb(reduced.comb, free)}`);
+    // Combinator normal forms are not always lambda normal forms, e.g. S K reads back as λx y. y
+    var back = normalize(comb_to_term(reduced.comb), { strategy: "normal_order", fuel: session.fuel, eta: true });
+    var agreement = back.$ !== "Normal" || expected.$ !== "Normal" ? "unknown"
+      : equal(back.term, expected.term) ? "agrees with normal order"
+      : "differs from normal order";
+    lines.push(`     back to λ: ${show({ term: back.term, free })}   (${agreement})`);
+    if (back.$ === "Normal") {
+      session.current = { term: back.term, free };
+    }
+  }
+  return print(lines.join("\n"));
+}
+
+// Type checks a System F term, then normalizes its erasure with the session's strategy
+// - session: the session state
+// - arg: the System F term's source
+// = the type and the normal form of the erased term, or why it is not well typed
+function show_system_f(session: Session, arg: string): Reply {
+  if (arg === "") {
+    return print("Usage: :systemf <term>, e.g. :systemf Λa. λ(x : a) x");
+  }
+  var parsed = parse_f(arg);
+  if (parsed.$ === "Err") {
+    return print(show_parse_error(arg, parsed.error));
+  }
+  var checked = check_f(parsed.term);
+  if (checked.$ === "Err") {
+    return print(`Type error: ${checked.error.message}`);
+  }
+  var erased = erase_f(parsed.term);
+  var result = normalize(erased, { ...rules(session), strategy: session.strategy, fuel: session.fuel });
+  session.current = { term: result.term, free: [] };
+  return print([
+    `: ${show_ftype(checked.type)}`,
+    `${show({ term: erased, free: [] })}`,
+    `${show(session.current)}   (${show_outcome(result)})`,
+  ].join("\n"));
+}
+
+// Type checks a term of the Calculus of Constructions, and normalizes it
+// - arg: the term's source
+// = the normal form of the term and of its type, or why it is not well typed
+function show_core_check(arg: string): Reply {
+  if (arg === "") {
+    return print("Usage: :core <term>, e.g. :core λ(a : Type) (x : a). x");
+  }
+  var parsed = parse_core(arg);
+  if (parsed.$ === "Err") {
+    return print(show_parse_error(arg, parsed.error));
+  }
+  var checked = check_core(parsed.term);
+  if (checked.$ === "Err") {
+    return print(`Type error: ${checked.message}`);
+  }
+  return print(`${show_core(normalize_core(parsed.term))} : ${show_core(checked.type)}`);
+}
+
+// Replaces the local definitions of a term by redexes, making the result the current term
+// Reducing the result duplicates the work that let shares
+// - session: the session state, whose current term is updated
+// - arg: the term's source, or empty for the current term
+// = the term without local definitions
+function desugar(session: Session, arg: string): Reply {
+  var open = target(session, arg);
+  if (typeof open === "string") {
+    return print(open);
+  }
+  session.current = { term: desugar_let(open.term), free: open.free };
+  return print(show(session.current));
+}
+
+// Shows or sets the evaluation strategy
+// - session: the session state
+// - arg: the strategy name, or "" to list them
+// = the available strategies, or a confirmation
+function set_strategy(session: Session, arg: string): Reply {
+  if (arg === "") {
+    return print(Object.keys(strategies)
+      .map(name => `${name === session.strategy ? "*" : " "} ${name}`)
+      .join("\n"));
+  }
+  if (!is_strategy(arg)) {
+    return print(`Unknown strategy ${arg}, try :strategy`);
+  }
+  session.strategy = arg;
+  return print(`Strategy set to ${arg}.`);
+}
+
+// Shows or sets eta reduction
+// - session: the session state
+// - arg: "on", "off", or "" to show the setting
+// = the setting
+function set_eta(session: Session, arg: string): Reply {
+  if (arg === "on" || arg === "off") {
+    session.eta = arg === "on";
+  } else if (arg !== "") {
+    return print("Usage: :eta [on|off]");
+  }
+  return print(`Eta reduction is ${session.eta ? "on" : "off"}.`);
+}
+
+// Shows or sets whether definitions are kept as references
+// - session: the session state
+// - arg: "on", "off", or "" to show the setting
+// = the setting
+function set_delta(session: Session, arg: string): Reply {
+  if (arg === "on" || arg === "off") {
+    session.delta = arg === "on";
+  } else if (arg !== "") {
+    return print("Usage: :delta [on|off]");
+  }
+  return print(session.delta
+    ? "Definitions are kept as constants, unfolded by the delta rule."
+    : "Definitions are inlined when a term is read.");
+}
+
+// Shows or sets the step budget
+// - session: the session state
+// - arg: the number of steps, or "" to show the setting
+// = the setting
+function set_fuel(session: Session, arg: string): Reply {
+  if (arg !== "") {
+    var fuel = Number(arg);
+    if (!Number.isInteger(fuel) || fuel < 0) {
+      return print("Usage: :fuel <steps>");
+    }
+    session.fuel = fuel;
+  }
+  return print(`Fuel is ${session.fuel} steps.`);
+}
+
+// Lists the definitions
+// - session: the session state
+// = one line per definition
+function show_defs(session: Session): Reply {
+  if (session.defs.size === 0) {
+    return print("No definitions.");
+  }
+  return print([...session.defs].map(([name, term]) => `${name} = ${show({ term, free: [] })}`).join("\n"));
+}
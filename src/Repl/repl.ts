@@ -0,0 +1,72 @@
+import * as fs from "fs";
+import * as os from "os";
+import * as path from "path";
+import * as readline from "readline";
+import { new_session, run_line } from "./command";
+import { Reply, Session } from "./_";
+
+// Where the REPL history is kept between sessions
+const HISTORY_FILE = path.join(os.homedir(), ".de_bruijn_history");
+
+// Starts an interactive REPL on the terminal, with line editing and history
+export function start_repl(): void {
+  const session = new_session();
+  const rl = readline.createInterface({
+    input: process.stdin,
+    output: process.stdout,
+    prompt: "λ> ",
+    history: load_history(),
+    historySize: 1000,
+  });
+
+  rl.on("history", (history: string[]) => save_history(history));
+
+  console.log("De Bruijn REPL. Type :help for the list of commands.");
+  rl.prompt();
+  rl.on("line", (line: string) => {
+    const reply = run_line_safely(session, line);
+    if (reply.$ === "Quit") {
+      rl.close();
+      return;
+    }
+    if (reply.text !== "") {
+      console.log(reply.text);
+    }
+    rl.prompt();
+  });
+}
+
+// Runs one line of REPL input, keeping the REPL alive when it exhausts the host stack
+// - session: the session state, updated in place
+// - line: the line typed by the user
+// = what to print, or whether to quit
+function run_line_safely(session: Session, line: string): Reply {
+  try {
+    return run_line(session, line);
+  } catch (e) {
+    if (e instanceof RangeError && /call stack/.test(e.message)) {
+      return { $: "Print", text: "Out of host stack: the term is too deep for this command" };
+    }
+    throw e;
+  }
+}
+
+// Loads the history of previous sessions, most recent first
+// = the history lines, or none if there is no history yet
+function load_history(): string[] {
+  try {
+    return fs.readFileSync(HISTORY_FILE, "utf8").split("\n").filter(line => line !== "");
+  } catch {
+    return [];
+  }
+}
+
+// Saves the history for the next sessions
+// - history: the history lines, most recent first
+function save_history(history: string[]): void {
+  try {
+    fs.writeFileSync(HISTORY_FILE, history.join("\n"));
+  } catch {
+    // The history is a convenience: failing to save it is not an error
+  }
+}
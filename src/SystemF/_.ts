@@ -0,0 +1,40 @@
+import { ParseError } from "../Parser/_";
+import { TypeError } from "../Type/_";
+
+// Represents a System F type, with type variables as de Bruijn indices
+// - TVar: a type variable, by the number of ∀ between it and its binder
+// - Arrow: the type of functions from one type to another
+// - Forall: a polymorphic type, e.g. ∀a. a → a
+export type FType
+  = { $: "TVar", index: number }
+  | { $: "Arrow", from: FType, to: FType }
+  | { $: "Forall", name?: string, body: FType };
+
+// Represents a System F term
+// Term and type variables are numbered separately: a term variable's index
+// counts the λ between it and its binder, a type variable's the Λ and ∀
+// - Var: a term variable, by de Bruijn index
+// - Lam: a function whose argument has the given type, e.g. λ(x : a) x
+// - App: the application of a term to another
+// - TLam: a type abstraction, e.g. Λa. λ(x : a) x
+// - TApp: the instantiation of a polymorphic term at a type, e.g. id [b]
+export type FTerm
+  = { $: "Var", index: number }
+  | { $: "Lam", name?: string, type: FType, body: FTerm }
+  | { $: "App", func: FTerm, arg: FTerm }
+  | { $: "TLam", name?: string, body: FTerm }
+  | { $: "TApp", func: FTerm, type: FType };
+
+// Represents the result of parsing a System F term
+// - Ok: the parsed term
+// - Err: why and where parsing failed
+export type FParseResult
+  = { $: "Ok", term: FTerm }
+  | { $: "Err", error: ParseError };
+
+// Represents the result of type checking a System F term
+// - Ok: the type of the term
+// - Err: why and where checking failed
+export type FCheck
+  = { $: "Ok", type: FType }
+  | { $: "Err", error: TypeError };
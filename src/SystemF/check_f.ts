@@ -0,0 +1,84 @@
+import { Path } from "../Term/_";
+import { is_type_error, type_error } from "../Type/error";
+import { FCheck, FTerm, FType } from "./_";
+import { ftype_equal, shift_type, subst_type } from "./subst_type";
+import { show_ftype, type_binder } from "./show_ftype";
+import { show_fterm } from "./show_fterm";
+
+// The typing context of a subterm
+// - vars: the type of each enclosing term binder, innermost last, with the
+//   number of type binders that enclosed it
+// - var_names: the names of the enclosing term binders, for messages
+// - types: the names of the enclosing type binders, innermost last
+type Context = { vars: Array<{ type: FType, depth: number }>, var_names: string[], types: string[] };
+
+// Type checks a closed System F term
+// Every binder is annotated, so each subterm has exactly one type: checking
+// only compares types, up to the names of bound type variables
+// - term: the FTerm to check
+// = the type of the term, or a TypeError locating the failure
+export function check_f(term: FTerm): FCheck {
+  try {
+    return { $: "Ok", type: check_at(term, { vars: [], var_names: [], types: [] }, []) };
+  } catch (e) {
+    if (is_type_error(e)) {
+      return { $: "Err", error: e };
+    }
+    throw e;
+  }
+}
+
+// Computes the type of a subterm
+// - term: the subterm
+// - ctx: the typing context of the subterm, extended in place and restored
+// - path: the path from the whole term to the subterm
+// = the type of the subterm
+function check_at(term: FTerm, ctx: Context, path: Path): FType {
+  switch (term.$) {
+    case "Var": {
+      var bound = ctx.vars[ctx.vars.length - 1 - term.index];
+      return shift_type(bound.type, ctx.types.length - bound.depth);
+    }
+    case "Lam": {
+      ctx.vars.push({ type: term.type, depth: ctx.types.length });
+      ctx.var_names.push(term.name ?? `x${ctx.var_names.length}`);
+      var body = check_at(term.body, ctx, [...path, "body"]);
+      ctx.vars.pop();
+      ctx.var_names.pop();
+      return { $: "Arrow", from: term.type, to: body };
+    }
+    case "App": {
+      var func = check_at(term.func, ctx, [...path, "func"]);
+      var arg = check_at(term.arg, ctx, [...path, "arg"]);
+      if (func.$ !== "Arrow") {
+        throw type_error(path, `${show(term.func, ctx)} has type ${show_ftype(func, ctx.types)}, which is not a function type`);
+      }
+      if (!ftype_equal(func.from, arg)) {
+        throw type_error(path, `${show(term.func, ctx)} expects an argument of type ${show_ftype(func.from, ctx.types)}, `
+          + `but ${show(term.arg, ctx)} has type ${show_ftype(arg, ctx.types)}`);
+      }
+      return func.to;
+    }
+    case "TLam": {
+      ctx.types.push(type_binder(term.name, ctx.types));
+      var body_type = check_at(term.body, ctx, [...path, "body"]);
+      var name = ctx.types.pop();
+      return { $: "Forall", name, body: body_type };
+    }
+    case "TApp": {
+      var poly = check_at(term.func, ctx, [...path, "func"]);
+      if (poly.$ !== "Forall") {
+        throw type_error(path, `${show(term.func, ctx)} has type ${show_ftype(poly, ctx.types)}, which is not polymorphic`);
+      }
+      return subst_type(poly.body, term.type);
+    }
+  }
+}
+
+// Shows a subterm in its context, for messages
+// - term: the subterm
+// - ctx: its typing context
+// = the string representation of the subterm
+function show(term: FTerm, ctx: Context): string {
+  return show_fterm(term, ctx.var_names, ctx.types);
+}
@@ -0,0 +1,27 @@
+import { Term } from "../Term/_";
+import { FTerm } from "./_";
+
+// Erases the types of a System F term, giving an untyped term with the same behaviour
+// Type abstractions and applications disappear, and lambdas lose their
+// annotations; term variables keep their indices, as they only count term binders
+// - term: the FTerm to erase
+// = the untyped Term
+export function erase_f(term: FTerm): Term {
+  switch (term.$) {
+    case "Var": {
+      return { $: "Var", index: term.index };
+    }
+    case "Lam": {
+      return { $: "Lam", name: term.name, body: erase_f(term.body) };
+    }
+    case "App": {
+      return { $: "App", func: erase_f(term.func), arg: erase_f(term.arg) };
+    }
+    case "TLam": {
+      return erase_f(term.body);
+    }
+    case "TApp": {
+      return erase_f(term.func);
+    }
+  }
+}
@@ -0,0 +1,196 @@
+import { FParseResult, FTerm, FType } from "./_";
+import { Cursor } from "../Parser/_";
+import { is_parse_error } from "../Parser/error";
+import { expect, fail, parse_identifier, peek_identifier, skip_whitespace } from "../Parser/cursor";
+
+// A parser for closed System F terms
+// Term lambdas annotate their binders, e.g. λ(f : a → a) (x : a). f (f x),
+// type lambdas are written Λa. M or /\a. M, and type applications M [T].
+// Types are written ∀a. T or forall a. T, with arrows → or -> to the right.
+// - input: the string to be parsed
+// = the parsed FTerm, or a ParseError locating the failure
+export const parse_f = (input: string): FParseResult => {
+  const cursor: Cursor = { input, index: 0 };
+  let term_vars: string[] = [];
+  let type_vars: string[] = [];
+
+  // Helper function to parse a term: an atom applied to terms and types
+  const parse_term = (): FTerm => {
+    skip_whitespace(cursor);
+
+    let term = parse_atom();
+
+    // Parse applications and type applications
+    while (true) {
+      skip_whitespace(cursor);
+      if (input[cursor.index] === '[') {
+        cursor.index++; // Skip '['
+        const type = parse_type();
+        expect(cursor, "]");
+        term = { $: "TApp", func: term, type };
+      } else if (starts_atom()) {
+        const arg = parse_atom();
+        term = { $: "App", func: term, arg };
+      } else {
+        break;
+      }
+    }
+
+    return term;
+  };
+
+  // Helper function to parse an atom (variable, lambda, type lambda, or parenthesized term)
+  const parse_atom = (): FTerm => {
+    skip_whitespace(cursor);
+
+    if (cursor.index >= input.length) {
+      return fail(cursor, ATOM, "unexpected end of input");
+    }
+
+    if (input[cursor.index] === 'Λ' || input.startsWith("/\\", cursor.index)) {
+      // Type abstraction, with one or more type binders
+      cursor.index += input[cursor.index] === 'Λ' ? 1 : 2;
+      const names = parse_names("a type variable after 'Λ'");
+      expect(cursor, ".");
+      type_vars.push(...names);
+      const body = parse_term();
+      type_vars.splice(type_vars.length - names.length);
+      return names.reduceRight<FTerm>((body, name) => ({ $: "TLam", name, body }), body);
+    }
+
+    switch (input[cursor.index]) {
+      case 'λ':
+      case '\\': {
+        // Lambda abstraction, with one or more annotated binders
+        cursor.index++; // Skip 'λ' or '\\'
+        const binders: Array<[string, FType]> = [];
+        do {
+          expect(cursor, "(");
+          const [name] = parse_names("a variable after '('", 1);
+          expect(cursor, ":");
+          const type = parse_type();
+          expect(cursor, ")");
+          binders.push([name, type]);
+          term_vars.push(name);
+          skip_whitespace(cursor);
+        } while (input[cursor.index] === '(');
+        if (input[cursor.index] === '.') {
+          cursor.index++; // Skip '.'
+        }
+        const body = parse_term();
+        term_vars.splice(term_vars.length - binders.length);
+        return binders.reduceRight<FTerm>((body, [name, type]) => ({ $: "Lam", name, type, body }), body);
+      }
+      case '(': {
+        // Parenthesized expression
+        cursor.index++; // Skip '('
+        const term = parse_term();
+        expect(cursor, ")");
+        return term;
+      }
+      default: {
+        // Variable
+        const start = cursor.index;
+        const name = parse_identifier(cursor);
+        if (!name) {
+          return fail(cursor, ATOM, `unexpected '${input[cursor.index]}'`);
+        }
+        const var_index = term_vars.lastIndexOf(name);
+        if (var_index === -1) {
+          return fail(cursor, [], `unbound variable '${name}'`, start);
+        }
+        return { $: "Var", index: term_vars.length - var_index - 1 };
+      }
+    }
+  };
+
+  // Helper function to parse a type: arrows associate to the right
+  const parse_type = (): FType => {
+    skip_whitespace(cursor);
+    const from = parse_type_atom();
+    skip_whitespace(cursor);
+    if (input[cursor.index] === '→' || input.startsWith("->", cursor.index)) {
+      cursor.index += input[cursor.index] === '→' ? 1 : 2;
+      const to = parse_type();
+      return { $: "Arrow", from, to };
+    }
+    return from;
+  };
+
+  // Helper function to parse a type atom (type variable, ∀, or parenthesized type)
+  const parse_type_atom = (): FType => {
+    skip_whitespace(cursor);
+    if (input[cursor.index] === '∀' || peek_identifier(cursor) === "forall") {
+      cursor.index += input[cursor.index] === '∀' ? 1 : "forall".length;
+      const names = parse_names("a type variable after '∀'");
+      expect(cursor, ".");
+      type_vars.push(...names);
+      const body = parse_type();
+      type_vars.splice(type_vars.length - names.length);
+      return names.reduceRight<FType>((body, name) => ({ $: "Forall", name, body }), body);
+    }
+    if (input[cursor.index] === '(') {
+      cursor.index++; // Skip '('
+      const type = parse_type();
+      expect(cursor, ")");
+      return type;
+    }
+    const start = cursor.index;
+    const name = parse_identifier(cursor);
+    if (!name) {
+      return fail(cursor, TYPE_ATOM, cursor.index >= input.length ? "unexpected end of input" : `unexpected '${input[cursor.index]}'`);
+    }
+    const var_index = type_vars.lastIndexOf(name);
+    if (var_index === -1) {
+      return fail(cursor, [], `unbound type variable '${name}'`, start);
+    }
+    return { $: "TVar", index: type_vars.length - var_index - 1 };
+  };
+
+  // Helper function to parse one or more names, up to a limit
+  const parse_names = (what: string, limit: number = Infinity): string[] => {
+    const names: string[] = [];
+    while (names.length < limit) {
+      skip_whitespace(cursor);
+      const start = cursor.index;
+      const name = parse_identifier(cursor);
+      if (!name) break;
+      if (name === "forall") {
+        return fail(cursor, ["name"], `unexpected keyword 'forall'`, start);
+      }
+      names.push(name);
+    }
+    if (names.length === 0) {
+      return fail(cursor, ["name"], `expected ${what}`);
+    }
+    return names;
+  };
+
+  // Helper function to check if the next character can start an atom
+  const starts_atom = (): boolean => {
+    return cursor.index < input.length && (/[λ\\Λ(a-zA-Z_]/.test(input[cursor.index]) || input.startsWith("/\\", cursor.index));
+  };
+
+  try {
+    // Parse the entire input
+    const term = parse_term();
+    skip_whitespace(cursor);
+
+    // Ensure we've consumed all input
+    if (cursor.index < input.length) {
+      return fail(cursor, [...ATOM, "[", "end of input"], `unexpected '${input[cursor.index]}'`);
+    }
+    return { $: "Ok", term };
+  } catch (e) {
+    if (is_parse_error(e)) {
+      return { $: "Err", error: e };
+    }
+    throw e;
+  }
+};
+
+// The tokens that can start a term atom
+const ATOM = ["variable", "λ", "Λ", "("];
+
+// The tokens that can start a type atom
+const TYPE_ATOM = ["type variable", "∀", "("];
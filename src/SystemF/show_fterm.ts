@@ -0,0 +1,38 @@
+import { FTerm } from "./_";
+import { show_ftype, type_binder } from "./show_ftype";
+
+// Converts a System F term to a string, e.g. Λa. λ(f : a → a) (x : a). f (f x)
+// - term: the term to show
+// - vars: the names of the term variables in scope, innermost last
+// - types: the names of the type variables in scope, innermost last
+// = the string representation of the term
+export function show_fterm(term: FTerm, vars: string[] = [], types: string[] = []): string {
+  switch (term.$) {
+    case "Var": {
+      return vars[vars.length - 1 - term.index] ?? `?${term.index}`;
+    }
+    case "Lam": {
+      var name = term.name ?? `x${vars.length}`;
+      return `λ(${name} : ${show_ftype(term.type, types)}). ${show_fterm(term.body, [...vars, name], types)}`;
+    }
+    case "App": {
+      var func = term.func.$ === "Lam" || term.func.$ === "TLam"
+        ? `(${show_fterm(term.func, vars, types)})`
+        : show_fterm(term.func, vars, types);
+      var arg = term.arg.$ === "Var"
+        ? show_fterm(term.arg, vars, types)
+        : `(${show_fterm(term.arg, vars, types)})`;
+      return `${func} ${arg}`;
+    }
+    case "TLam": {
+      var type_name = type_binder(term.name, types);
+      return `Λ${type_name}. ${show_fterm(term.body, vars, [...types, type_name])}`;
+    }
+    case "TApp": {
+      var poly = term.func.$ === "Lam" || term.func.$ === "TLam"
+        ? `(${show_fterm(term.func, vars, types)})`
+        : show_fterm(term.func, vars, types);
+      return `${poly} [${show_ftype(term.type, types)}]`;
+    }
+  }
+}
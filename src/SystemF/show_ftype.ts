@@ -0,0 +1,35 @@
+import { FType } from "./_";
+
+// Converts a System F type to a string, e.g. ∀a. (a → a) → a → a
+// Bound variables keep their names, primed when they would shadow a variable in scope
+// - type: the type to show
+// - names: the names of the type variables in scope, innermost last
+// = the string representation of the type
+export function show_ftype(type: FType, names: string[] = []): string {
+  switch (type.$) {
+    case "TVar": {
+      return names[names.length - 1 - type.index] ?? `?${type.index}`;
+    }
+    case "Arrow": {
+      var from = show_ftype(type.from, names);
+      var to = show_ftype(type.to, names);
+      return type.from.$ === "TVar" ? `${from} → ${to}` : `(${from}) → ${to}`;
+    }
+    case "Forall": {
+      var name = type_binder(type.name, names);
+      return `∀${name}. ${show_ftype(type.body, [...names, name])}`;
+    }
+  }
+}
+
+// Picks the name of a type binder
+// - name: the name it was written with, if any
+// - names: the names in scope
+// = the name, primed until it does not shadow another
+export function type_binder(name: string | undefined, names: string[]): string {
+  var chosen = name ?? "a";
+  while (names.includes(chosen)) {
+    chosen += "'";
+  }
+  return chosen;
+}
@@ -0,0 +1,60 @@
+import { FType } from "./_";
+
+// Shifts the free type variables of a type
+// - type: the type to shift
+// - by: the amount to shift by
+// - from: the cutoff index, below which variables are bound inside the type
+// = the shifted type
+export function shift_type(type: FType, by: number, from: number = 0): FType {
+  switch (type.$) {
+    case "TVar": {
+      return { $: "TVar", index: type.index < from ? type.index : type.index + by };
+    }
+    case "Arrow": {
+      return { $: "Arrow", from: shift_type(type.from, by, from), to: shift_type(type.to, by, from) };
+    }
+    case "Forall": {
+      return { $: "Forall", name: type.name, body: shift_type(type.body, by, from + 1) };
+    }
+  }
+}
+
+// Instantiates the body of a ∀ with a type
+// - body: the body of the ∀, whose variable 0 is the quantified one
+// - arg: the type to put in its place
+// - depth: the number of ∀ entered inside the body
+// = the body with the quantified variable replaced, and the ∀ removed
+export function subst_type(body: FType, arg: FType, depth: number = 0): FType {
+  switch (body.$) {
+    case "TVar": {
+      if (body.index === depth) {
+        return shift_type(arg, depth);
+      }
+      return body.index > depth ? { $: "TVar", index: body.index - 1 } : body;
+    }
+    case "Arrow": {
+      return { $: "Arrow", from: subst_type(body.from, arg, depth), to: subst_type(body.to, arg, depth) };
+    }
+    case "Forall": {
+      return { $: "Forall", name: body.name, body: subst_type(body.body, arg, depth + 1) };
+    }
+  }
+}
+
+// Checks if two types are equal, up to the names of their bound variables
+// - a: a type
+// - b: another type
+// = true if the types are the same
+export function ftype_equal(a: FType, b: FType): boolean {
+  switch (a.$) {
+    case "TVar": {
+      return b.$ === "TVar" && a.index === b.index;
+    }
+    case "Arrow": {
+      return b.$ === "Arrow" && ftype_equal(a.from, b.from) && ftype_equal(a.to, b.to);
+    }
+    case "Forall": {
+      return b.$ === "Forall" && ftype_equal(a.body, b.body);
+    }
+  }
+}
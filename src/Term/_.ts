@@ -1,9 +1,19 @@
 // Represents a lambda calculus term using de Bruijn indices
 // - Var: a variable, represented by its de Bruijn index
-// - Lam: a lambda abstraction
+// - Lam: a lambda abstraction, optionally remembering the name of its binder
 // - App: an application of one term to another
+// - Ref: a reference to a top-level definition, unfolded by the delta rule
+// - Let: a local definition (let x = value in body), whose body is under a binder
 
 export type Term
   = { $: "Var", index: number }
-  | { $: "Lam", body: Term }
-  | { $: "App", func: Term, arg: Term };
+  | { $: "Lam", body: Term, name?: string }
+  | { $: "App", func: Term, arg: Term }
+  | { $: "Ref", name: string }
+  | { $: "Let", value: Term, body: Term, name?: string };
+
+// A path from the root of a term down to one of its subterms
+// - func/arg: into the function or the argument of an App
+// - body: into the body of a Lam or a Let
+// - value: into the bound value of a Let
+export type Path = Array<"func" | "arg" | "body" | "value">;
@@ -0,0 +1,12 @@
+import { Term } from "./_";
+
+// Builds the Church numeral of a natural number, λf x. f (f ... (f x))
+// - n: the number
+// = the numeral, applying f n times
+export function church_numeral(n: number): Term {
+  var body: Term = { $: "Var", index: 0 };
+  for (var i = 0; i < n; i++) {
+    body = { $: "App", func: { $: "Var", index: 1 }, arg: body };
+  }
+  return { $: "Lam", name: "f", body: { $: "Lam", name: "x", body } };
+}
@@ -0,0 +1,28 @@
+import { Term } from "./_";
+
+// Replaces every local definition let x = M in N by the redex (λx N) M
+// - term: the term to desugar
+// = an equivalent term without Let nodes
+export function desugar_let(term: Term): Term {
+  switch (term.$) {
+    case "Var": {
+      return term;
+    }
+    case "Lam": {
+      return { $: "Lam", name: term.name, body: desugar_let(term.body) };
+    }
+    case "App": {
+      return { $: "App", func: desugar_let(term.func), arg: desugar_let(term.arg) };
+    }
+    case "Let": {
+      return {
+        $: "App",
+        func: { $: "Lam", name: term.name, body: desugar_let(term.body) },
+        arg: desugar_let(term.value)
+      };
+    }
+    case "Ref": {
+      return term;
+    }
+  }
+}
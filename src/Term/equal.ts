@@ -0,0 +1,26 @@
+import { Term } from "./_";
+
+// Checks if two terms are syntactically equal
+// Since binders are nameless, this is exactly alpha-equivalence
+// - a: the first term
+// - b: the second term
+// = true if both terms are the same
+export function equal(a: Term, b: Term): boolean {
+  switch (a.$) {
+    case "Var": {
+      return b.$ === "Var" && a.index === b.index;
+    }
+    case "Lam": {
+      return b.$ === "Lam" && equal(a.body, b.body);
+    }
+    case "App": {
+      return b.$ === "App" && equal(a.func, b.func) && equal(a.arg, b.arg);
+    }
+    case "Let": {
+      return b.$ === "Let" && equal(a.value, b.value) && equal(a.body, b.body);
+    }
+    case "Ref": {
+      return b.$ === "Ref" && a.name === b.name;
+    }
+  }
+}
@@ -0,0 +1,26 @@
+import { Term } from "./_";
+
+// Counts the occurrences of a variable in a term
+// - index: the de Bruijn index of the variable, relative to the term
+// - term: the term to look in
+// = the number of times the variable occurs
+export function occurrences(index: number, term: Term): number {
+  switch (term.$) {
+    case "Var": {
+      return term.index === index ? 1 : 0;
+    }
+    case "Lam": {
+      return occurrences(index + 1, term.body);
+    }
+    case "App": {
+      return occurrences(index, term.func) + occurrences(index, term.arg);
+    }
+    case "Let": {
+      return occurrences(index, term.value) + occurrences(index + 1, term.body);
+    }
+    case "Ref": {
+      // Definitions are closed
+      return 0;
+    }
+  }
+}
@@ -0,0 +1,26 @@
+import { Term } from "./_";
+
+// Checks if a variable occurs free in a term
+// - index: the de Bruijn index of the variable, relative to the term
+// - term: the term to look in
+// = true if the variable occurs in the term
+export function occurs_free(index: number, term: Term): boolean {
+  switch (term.$) {
+    case "Var": {
+      return term.index === index;
+    }
+    case "Lam": {
+      return occurs_free(index + 1, term.body);
+    }
+    case "App": {
+      return occurs_free(index, term.func) || occurs_free(index, term.arg);
+    }
+    case "Let": {
+      return occurs_free(index, term.value) || occurs_free(index + 1, term.body);
+    }
+    case "Ref": {
+      // Definitions are closed
+      return false;
+    }
+  }
+}
@@ -0,0 +1,41 @@
+import { Path, Term } from "./_";
+
+// Turns a path into a key for maps of subterms, e.g. "body func"
+// - path: the path from a whole term down to a subterm
+// = the key, the empty string for the whole term
+export function path_key(path: Path): string {
+  return path.join(" ");
+}
+
+// Rekeys what is known of the nodes of a term by their paths
+// Unlike the nodes themselves, paths survive copying the term
+// - term: the whole term
+// - nodes: what is known of some of its nodes, by node
+// = the same, keyed by the path_key of each node
+export function key_by_path<T>(term: Term, nodes: Map<Term, T>): Map<string, T> {
+  var keyed = new Map<string, T>();
+  var stack: Array<[Term, string]> = [[term, ""]];
+  while (stack.length > 0) {
+    var [node, key] = stack.pop()!;
+    var known = nodes.get(node);
+    if (known !== undefined) {
+      keyed.set(key, known);
+    }
+    var prefix = key === "" ? "" : `${key} `;
+    switch (node.$) {
+      case "Lam": {
+        stack.push([node.body, `${prefix}body`]);
+        break;
+      }
+      case "App": {
+        stack.push([node.func, `${prefix}func`], [node.arg, `${prefix}arg`]);
+        break;
+      }
+      case "Let": {
+        stack.push([node.value, `${prefix}value`], [node.body, `${prefix}body`]);
+        break;
+      }
+    }
+  }
+  return keyed;
+}
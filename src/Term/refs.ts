@@ -0,0 +1,33 @@
+import { Term } from "./_";
+
+// Collects the names of the definitions a term refers to
+// Shared subterms, as in inlined definitions, are only visited once
+// - term: the term to inspect
+// - names: the set to add the names to
+// - seen: the subterms visited so far
+// = the set of names
+export function refs(term: Term, names: Set<string> = new Set(), seen: Set<Term> = new Set()): Set<string> {
+  if (seen.has(term)) {
+    return names;
+  }
+  seen.add(term);
+  switch (term.$) {
+    case "Var": {
+      return names;
+    }
+    case "Lam": {
+      return refs(term.body, names, seen);
+    }
+    case "App": {
+      refs(term.func, names, seen);
+      return refs(term.arg, names, seen);
+    }
+    case "Let": {
+      refs(term.value, names, seen);
+      return refs(term.body, names, seen);
+    }
+    case "Ref": {
+      return names.add(term.name);
+    }
+  }
+}
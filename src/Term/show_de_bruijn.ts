@@ -1,25 +1,51 @@
-import { Term } from "./_";
+import { Path, Term } from "./_";
 
 // Pretty prints a lambda calculus term using de Bruijn notation
 // - term: the Term to be printed
+// - options.free: names of the free variables, printed instead of their indices
+// - options.highlight: paths to subterms to be wrapped in [brackets], e.g. the redexes of a step
 // = a string representation of the term in de Bruijn notation
-export function show_de_bruijn(term: Term): string {
-  const show_term = (t: Term, parent_precedence: number): string => {
+export function show_de_bruijn(term: Term, options: { free?: string[], highlight?: Path[] } = {}): string {
+  const free   = options.free ?? [];
+  const marked = new Set((options.highlight ?? []).map(path => path.join(".")));
+
+  const show_term = (t: Term, parent_precedence: number, path: string, depth: number): string => {
+    if (marked.has(path)) {
+      return `[${show_plain(t, 0, path, depth)}]`;
+    }
+    return show_plain(t, parent_precedence, path, depth);
+  };
+
+  const show_plain = (t: Term, parent_precedence: number, path: string, depth: number): string => {
     switch (t.$) {
       case "Var": {
-        return t.index.toString();
+        return t.index >= depth && t.index - depth < free.length
+          ? free[t.index - depth]
+          : t.index.toString();
       }
       case "Lam": {
-        var body = show_term(t.body, 0);
+        var body = show_term(t.body, 0, child(path, "body"), depth + 1);
         return parent_precedence > 0 ? `(λ${body})` : `λ${body}`;
       }
       case "App": {
-        var func = show_term(t.func, 1);
-        var arg  = show_term(t.arg, 2);
+        var func = show_term(t.func, 1, child(path, "func"), depth);
+        var arg  = show_term(t.arg, 2, child(path, "arg"), depth);
         return parent_precedence > 1 ? `(${func} ${arg})` : `${func} ${arg}`;
       }
+      case "Let": {
+        var value = show_term(t.value, 0, child(path, "value"), depth);
+        var body  = show_term(t.body, 0, child(path, "body"), depth + 1);
+        return parent_precedence > 0 ? `(let ${value} in ${body})` : `let ${value} in ${body}`;
+      }
+      case "Ref": {
+        return t.name;
+      }
     }
   };
 
-  return show_term(term, 0);
+  const child = (path: string, dir: Path[number]): string => {
+    return path === "" ? dir : `${path}.${dir}`;
+  };
+
+  return show_term(term, 0, "", 0);
 }
@@ -0,0 +1,119 @@
+import { Term } from "./_";
+import { refs } from "./refs";
+
+// Pretty prints a lambda calculus term using named variables
+// Binders are given names that never clash with free variables, definitions
+// or enclosing binders, so the output always parses back to the same term
+// - term: the Term to be printed
+// - options.free: names of the free variables
+// - options.reuse_names: whether to reuse the binder names recorded by the parser, true by default
+// - options.ascii: whether to print lambdas as '\' instead of 'λ'
+// = a string representation of the term in named notation, e.g. λf x. f (f x)
+export function show_named(term: Term, options: { free?: string[], reuse_names?: boolean, ascii?: boolean } = {}): string {
+  const free   = [...(options.free ?? [])];
+  const reuse  = options.reuse_names ?? true;
+  const lambda = options.ascii ? "\\" : "λ";
+
+  // Free variables without a recorded name get fresh ones
+  const defs  = refs(term);
+  const taken = new Set([...free, ...defs]);
+  for (let index = free.length; index <= max_free_index(term, 0); index++) {
+    const name = fresh("v", taken);
+    free.push(name);
+    taken.add(name);
+  }
+
+  // scope: the names of the enclosing binders, innermost last
+  const show_term = (t: Term, parent_precedence: number, scope: string[]): string => {
+    switch (t.$) {
+      case "Var": {
+        return t.index < scope.length
+          ? scope[scope.length - 1 - t.index]
+          : free[t.index - scope.length];
+      }
+      case "Lam": {
+        // Consecutive lambdas share a single λ
+        var names: string[] = [];
+        var inner: Term = t;
+        var inner_scope = scope;
+        while (inner.$ === "Lam") {
+          var avoid = new Set([...free, ...defs, ...inner_scope]);
+          var name  = fresh(reuse && inner.name ? inner.name : default_name(inner_scope.length), avoid);
+          names.push(name);
+          inner_scope = [...inner_scope, name];
+          inner = inner.body;
+        }
+        var body = show_term(inner, 0, inner_scope);
+        var lam  = `${lambda}${names.join(" ")}. ${body}`;
+        return parent_precedence > 0 ? `(${lam})` : lam;
+      }
+      case "App": {
+        var func = show_term(t.func, 1, scope);
+        var arg  = show_term(t.arg, 2, scope);
+        return parent_precedence > 1 ? `(${func} ${arg})` : `${func} ${arg}`;
+      }
+      case "Let": {
+        var avoid = new Set([...free, ...defs, ...scope]);
+        var name  = fresh(reuse && t.name ? t.name : default_name(scope.length), avoid);
+        var value = show_term(t.value, 0, scope);
+        var body  = show_term(t.body, 0, [...scope, name]);
+        var let_  = `let ${name} = ${value} in ${body}`;
+        return parent_precedence > 0 ? `(${let_})` : let_;
+      }
+      case "Ref": {
+        return t.name;
+      }
+    }
+  };
+
+  return show_term(term, 0, []);
+}
+
+// Picks the default name of a binder from its depth: a, b, ..., z, a1, b1, ...
+// - depth: the number of enclosing binders
+// = the default name
+function default_name(depth: number): string {
+  var letter = String.fromCharCode(97 + depth % 26);
+  var round  = Math.floor(depth / 26);
+  return round === 0 ? letter : `${letter}${round}`;
+}
+
+// Finds a name not in a set, based on a preferred name
+// - base: the preferred name
+// - avoid: the names already in use
+// = base itself if available, otherwise base followed by the smallest free number
+function fresh(base: string, avoid: Set<string>): string {
+  if (!avoid.has(base)) {
+    return base;
+  }
+  var stem = base.replace(/[0-9]+$/, "");
+  var n = 1;
+  while (avoid.has(`${stem}${n}`)) {
+    n++;
+  }
+  return `${stem}${n}`;
+}
+
+// Finds the largest free variable index of a term, relative to the term
+// - term: the term to inspect
+// - depth: the number of binders crossed so far
+// = the largest index of a free variable, or -1 if the term is closed
+function max_free_index(term: Term, depth: number): number {
+  switch (term.$) {
+    case "Var": {
+      return term.index >= depth ? term.index - depth : -1;
+    }
+    case "Lam": {
+      return max_free_index(term.body, depth + 1);
+    }
+    case "App": {
+      return Math.max(max_free_index(term.func, depth), max_free_index(term.arg, depth));
+    }
+    case "Let": {
+      return Math.max(max_free_index(term.value, depth), max_free_index(term.body, depth + 1));
+    }
+    case "Ref": {
+      return -1;
+    }
+  }
+}
@@ -0,0 +1,24 @@
+import { Term } from "./_";
+
+// Measures a term
+// - term: the term
+// = its number of nodes, variables, lambdas, applications and lets included
+export function size(term: Term): number {
+  switch (term.$) {
+    case "Var": {
+      return 1;
+    }
+    case "Lam": {
+      return 1 + size(term.body);
+    }
+    case "App": {
+      return 1 + size(term.func) + size(term.arg);
+    }
+    case "Let": {
+      return 1 + size(term.value) + size(term.body);
+    }
+    case "Ref": {
+      return 1;
+    }
+  }
+}
@@ -0,0 +1,5 @@
+// Represents one executable check
+// - name: what is being checked
+// - run: performs the check
+//   = null if it passes, or a description of the failure
+export type Test = { name: string, run: () => string | null };
@@ -0,0 +1,82 @@
+import { Test } from "./_";
+import { check } from "../Type/check";
+import { show_type } from "../Type/show_type";
+import { with_term } from "./with_term";
+import { doubling } from "./doubling";
+import { parse } from "../Parser/parse";
+import { path_key } from "../Term/path_key";
+
+// Checks a term against the definitions of a source
+// - source: the definitions
+// - code: the term to check
+// = the type of the term, or the message of its error
+function check_with(source: string, code: string): string {
+  return with_term(code, source, (parsed, program) => {
+    var checked = check(parsed.term, parsed.annotations, program.annotated);
+    return checked.$ === "Ok" ? show_type(checked.type, new Map(parsed.annotations.names)) : checked.error.message;
+  });
+}
+
+export const check_tests: Test[] = [
+  {
+    name: "definitions keep their annotations",
+    run: () => {
+      var found = check_with("app = λ(f : a -> b) (x : a). f x;", "app");
+      return found === "(a -> b) -> a -> b" ? null : `app has type ${found}`;
+    },
+  },
+  {
+    name: "unannotated definitions are checked against the expected type",
+    run: () => {
+      var source = "id = λx x; k = λx y. x; twice = λ(f : a -> a) (x : a). f (f x);";
+      var found = check_with(source, "λ(z : a) twice id (twice id z)");
+      if (found !== "a -> a") {
+        return `λz. twice id (twice id z) has type ${found}`;
+      }
+      var wrong = check_with(source, "(k : a -> b -> b)");
+      return wrong.startsWith("k does not have type a -> b -> b") ? null : `k : a -> b -> b gives ${wrong}`;
+    },
+  },
+  {
+    name: "each definition is checked once",
+    run: () => {
+      // Checked at every reference, d60 would take 2^59 checks of d1
+      var found = check_with(doubling(60, "λ(x : a) x", d => `λ(x : a) ${d} (${d} x)`), "d60");
+      return found === "a -> a" ? null : `d60 has type ${found}`;
+    },
+  },
+  {
+    name: "several annotated binders are ended by a dot",
+    run: () => {
+      var found = check_with("", "λ(f : a -> a) (x : a). f x");
+      if (found !== "(a -> a) -> a -> a") {
+        return `λ(f : a -> a) (x : a). f x has type ${found}`;
+      }
+      var body = check_with("", "λ(x : a). (x : a)");
+      if (body !== "a -> a") {
+        return `λ(x : a). (x : a) has type ${body}`;
+      }
+      var missing = parse("λ(x : a) (y : b) x");
+      return missing.$ === "Err" && missing.error.message.startsWith("expected '.'")
+        ? null
+        : "λ(x : a) (y : b) x parses without its dot";
+    },
+  },
+  {
+    name: "type errors are located by the path of their subterm",
+    run: () => {
+      var code = "λ(f : a -> b) (x : b). f x";
+      var parsed = parse(code);
+      if (parsed.$ === "Err") {
+        return parsed.error.message;
+      }
+      var checked = check(parsed.term, parsed.annotations);
+      if (checked.$ === "Ok") {
+        return `${code} has type ${show_type(checked.type)}`;
+      }
+      var span = parsed.spans.get(path_key(checked.error.path));
+      var found = span ? code.slice(span.start, span.end) : "nothing";
+      return found === "x" ? null : `the error underlines ${found}`;
+    },
+  },
+];
@@ -0,0 +1,50 @@
+import { Test } from "./_";
+import { Basis } from "../Combinator/_";
+import { equal } from "../Term/equal";
+import { show_de_bruijn } from "../Term/show_de_bruijn";
+import { normalize } from "../Reducer/normalize";
+import { compile_comb } from "../Combinator/compile_comb";
+import { reduce_comb } from "../Combinator/reduce_comb";
+import { comb_to_term } from "../Combinator/comb_to_term";
+import { show_comb } from "../Combinator/show_comb";
+import { random_terms } from "./random_term";
+import { with_term } from "./with_term";
+
+// The bases every property is checked in
+const BASES: Basis[] = ["SKI", "BCKW"];
+
+export const comb_tests: Test[] = [
+  ...BASES.map(basis => ({
+    name: `${basis} reduction agrees with normal order`,
+    run: () => {
+      var generate = random_terms(3, { size: 12 });
+      for (var i = 0; i < 300; i++) {
+        var term = generate();
+        // Turner's optimizations eta-reduce, so normal forms are compared up to eta
+        var expected = normalize(term, { fuel: 200, eta: true });
+        var reduced = reduce_comb(compile_comb(term, basis), 2000);
+        if (expected.$ !== "Normal" || reduced.$ !== "Normal") {
+          continue;
+        }
+        var back = normalize(comb_to_term(reduced.comb), { fuel: 2000, eta: true });
+        if (back.$ === "Normal" && !equal(back.term, expected.term)) {
+          return `${show_de_bruijn(term)} reads back as ${show_de_bruijn(back.term)}`;
+        }
+      }
+      return null;
+    },
+  })),
+  ...BASES.map(basis => ({
+    name: `${basis} reduction returns the expression it reached when out of fuel`,
+    run: () => {
+      return with_term("(λx. x x) (λx. x x)", "", parsed => {
+        var comb = compile_comb(parsed.term, basis);
+        var reduced = reduce_comb(comb, 3);
+        if (reduced.$ !== "OutOfFuel" || reduced.steps !== 3) {
+          return `got ${reduced.$} after ${reduced.steps} steps`;
+        }
+        return show_comb(reduced.comb) === show_comb(comb) ? "the input was returned unreduced" : null;
+      });
+    },
+  })),
+];
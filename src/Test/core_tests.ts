@@ -0,0 +1,60 @@
+import { Test } from "./_";
+import { parse_core } from "../Core/parse_core";
+import { check_core } from "../Core/check_core";
+import { show_core } from "../Core/show_core";
+
+// Type checks a term of the Calculus of Constructions
+// - code: the term's source
+// = its type, or the message of its error
+function check_source(code: string): string {
+  var parsed = parse_core(code);
+  if (parsed.$ === "Err") {
+    return `parse error: ${parsed.error.message}`;
+  }
+  var checked = check_core(parsed.term);
+  return checked.$ === "Ok" ? show_core(checked.type) : `error: ${checked.message}`;
+}
+
+// Terms and their types
+const CASES: [string, string][] = [
+  ["λ(A : Type) (P : A → Type) (x : A) (p : P x). p", "Π(A : Type). Π(P : A → Type). Π(x : A). P x → P x"],
+  // Types of types are sorts, and Kind itself is not typed
+  ["Type", "Kind"],
+  ["Type → Type", "Kind"],
+  ["Kind", "error: Kind has no type"],
+  // Conversion fails between distinct variables, and between distinct applications of a family
+  ["λ(A : Type) (B : Type) (x : A). (λ(y : B) y) x", "error: λ(y : B). y expects an argument of type B, but x has type A"],
+  ["λ(A : Type) (P : A → Type) (x : A) (y : A) (p : P x). (λ(q : P y) q) p", "error: λ(q : P y). q expects an argument of type P y, but p has type P x"],
+  // Conversion succeeds up to beta
+  ["λ(F : Type → Type) (A : Type) (x : F ((λ(B : Type) B) A)). (λ(y : F A) y) x", "Π(F : Type → Type). Π(A : Type). F A → F A"],
+];
+
+export const core_tests: Test[] = [
+  ...CASES.map(([code, wanted]) => ({
+    name: `check ${code}`,
+    run: () => {
+      var found = check_source(code);
+      return found === wanted ? null : `got ${found}`;
+    },
+  })),
+  {
+    name: "inferred types parse back",
+    run: () => {
+      for (var [code] of CASES) {
+        var parsed = parse_core(code);
+        var checked = parsed.$ === "Ok" ? check_core(parsed.term) : null;
+        if (checked?.$ === "Ok") {
+          var type = show_core(checked.type);
+          var again = parse_core(type);
+          if (again.$ === "Err") {
+            return `${type} does not parse: ${again.error.message}`;
+          }
+          if (show_core(again.term) !== type) {
+            return `${type} parses as ${show_core(again.term)}`;
+          }
+        }
+      }
+      return null;
+    },
+  },
+];
@@ -0,0 +1,26 @@
+import { Test } from "./_";
+import { decode } from "../Data/decode";
+import { show_data } from "../Data/show_data";
+import { with_term } from "./with_term";
+
+// Terms and their readings, in the order decode lists them
+const CASES: [string, string][] = [
+  ["λf x. f (f (f x))", "3"],
+  ["λt f. f", "0 | false | []"],
+  ["λt f. t", "true | scott 0"],
+  ["λz s. s (λz s. s (λz s. z))", "scott 2"],
+  ["λs. s (λf x. f x) (λt f. t)", "(1, true)"],
+  ["λs. s (λz s. z) (λz s. s (λz s. z))", "(true, scott 1)"],
+  ["λc n. c (λf x. f x) (c (λf x. f (f x)) n)", "[1,2]"],
+  ["λc n. c (λt f. f) n", "[0]"],
+  ["λs. s (λx. x) (λt f. t)", "(λx. x, true)"],
+  ["λx. x", ""],
+];
+
+export const decode_tests: Test[] = CASES.map(([code, wanted]) => ({
+  name: `decode ${code}`,
+  run: () => {
+    var found = with_term(code, "", parsed => decode(parsed.term).map(data => show_data(data)).join(" | "));
+    return found === wanted ? null : `read as ${found}`;
+  },
+}));
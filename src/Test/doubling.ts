@@ -0,0 +1,13 @@
+// Writes a chain of definitions, each using the previous one twice
+// Written out without sharing, the last definition is exponentially large
+// - length: the number of definitions
+// - first: the body of d1
+// - double: the body of each next definition, given the name of the previous one
+// = the source, one definition per line, e.g. d1 = λx x; d2 = d1 d1; ...
+export function doubling(length: number, first: string, double: (previous: string) => string): string {
+  var lines = [`d1 = ${first};`];
+  for (var i = 2; i <= length; i++) {
+    lines.push(`d${i} = ${double(`d${i - 1}`)};`);
+  }
+  return lines.join("\n");
+}
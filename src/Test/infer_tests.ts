@@ -0,0 +1,44 @@
+import { Test } from "./_";
+import { infer } from "../Type/infer";
+import { show_type } from "../Type/show_type";
+import { with_term } from "./with_term";
+
+// The definitions the terms below refer to
+const DEFS = "id = λx x; two = λf x. f (f x);";
+
+// Infers the simple type of a term
+// - code: the term's source
+// = its type, then the types of its free variables, or the error and its path
+function infer_type(code: string): string {
+  return with_term(code, DEFS, (parsed, program) => {
+    var inferred = infer(parsed.term, program.defs);
+    if (inferred.$ === "Err") {
+      return `error at [${inferred.error.path.join(", ")}]: ${inferred.error.message}`;
+    }
+    var names = new Map<number, string>();
+    var type = show_type(inferred.type, names);
+    var free = inferred.free.map((free_type, index) => `${parsed.free[index]} : ${show_type(free_type, names)}`);
+    return [type, ...free].join(", ");
+  });
+}
+
+// Terms and their types
+const CASES: [string, string][] = [
+  ["λf x. f x", "(a -> b) -> a -> b"],
+  ["λf g x. f (g x)", "(a -> b) -> (c -> a) -> c -> b"],
+  ["λx y. x", "a -> b -> a"],
+  ["λx x x", "error at [body]: cannot apply a function of type a to an argument of type a: infinite type a = a -> b"],
+  ["λf. f (λx x x)", "error at [body, arg, body]: cannot apply a function of type a to an argument of type a: infinite type a = a -> b"],
+  ["f x", "a, f : b -> a, x : b"],
+  ["λy. f (f y)", "a -> a, f : a -> a"],
+  ["two", "(a -> a) -> a -> a"],
+  ["two id", "a -> a"],
+];
+
+export const infer_tests: Test[] = CASES.map(([code, wanted]) => ({
+  name: `infer ${code}`,
+  run: () => {
+    var found = infer_type(code);
+    return found === wanted ? null : `got ${found}`;
+  },
+}));
@@ -0,0 +1,55 @@
+import { Test } from "./_";
+import { load_source } from "../Program/load";
+import { refs } from "../Term/refs";
+import { equal } from "../Term/equal";
+import { parse } from "../Parser/parse";
+import { doubling } from "./doubling";
+
+// A chain of definitions, each applying the previous one to itself
+const CHAIN = doubling(100, "λx x", d => `${d} ${d}`);
+
+export const inline_tests: Test[] = [
+  {
+    name: "inlining leaves no references",
+    run: () => {
+      var loaded = load_source("two = λf x. f (f x); four = two two; id = λx x; main = four id;", new Map(), { inline: true });
+      if (loaded.$ === "Err") {
+        return loaded.error.message;
+      }
+      var main = loaded.program.defs.get("main")!;
+      var wanted = parse("(λf x. f (f x)) (λf x. f (f x)) (λx x)");
+      if (wanted.$ === "Err" || refs(main).size > 0 || !equal(main, wanted.term)) {
+        return "main is not inlined to two two id";
+      }
+      return null;
+    },
+  },
+  {
+    name: "inlining shares each definition",
+    run: () => {
+      // Unshared, the last definition would have 2^99 leaves
+      var loaded = load_source(CHAIN, new Map(), { inline: true });
+      if (loaded.$ === "Err") {
+        return loaded.error.message;
+      }
+      var d100 = loaded.program.defs.get("d100")!;
+      return d100.$ === "App" && d100.func === d100.arg ? null : "the two halves of d100 are separate copies";
+    },
+  },
+  {
+    name: "inlining against inlined definitions shares them",
+    run: () => {
+      var known = load_source(CHAIN, new Map(), { inline: true });
+      if (known.$ === "Err") {
+        return known.error.message;
+      }
+      // Walked as a tree, d100 would have 2^99 leaves
+      var loaded = load_source("d101 = d100 d100;", known.program.defs, { inline: true });
+      if (loaded.$ === "Err") {
+        return loaded.error.message;
+      }
+      var d101 = loaded.program.defs.get("d101")!;
+      return d101.$ === "App" && d101.func === known.program.defs.get("d100") ? null : "d101 copies d100";
+    },
+  },
+];
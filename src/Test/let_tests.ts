@@ -0,0 +1,63 @@
+import { Test } from "./_";
+import { equal } from "../Term/equal";
+import { show_de_bruijn } from "../Term/show_de_bruijn";
+import { desugar_let } from "../Term/desugar_let";
+import { normalize } from "../Reducer/normalize";
+import { Strategy } from "../Reducer/_";
+import { random_terms } from "./random_term";
+import { with_term } from "./with_term";
+
+// Counts the beta steps a strategy takes to normalize a term
+// - code: the term
+// - strategy: the strategy to follow
+// = the number of beta steps, or null if the term does not normalize
+function beta_steps(code: string, strategy: Strategy): number | null {
+  var steps = 0;
+  var reduced = with_term(code, "", parsed => normalize(parsed.term, {
+    strategy,
+    fuel: 100,
+    on_step: step => {
+      steps += step.redexes.filter(redex => redex.rule === "beta").length;
+    },
+  }));
+  return typeof reduced !== "string" && reduced.$ === "Normal" ? steps : null;
+}
+
+export const let_tests: Test[] = [
+  {
+    name: "let and its desugaring have the same normal form",
+    run: () => {
+      var generate = random_terms(2, { size: 15 });
+      for (var i = 0; i < 1000; i++) {
+        var term = generate();
+        var shared = normalize(term, { fuel: 200 });
+        var desugared = normalize(desugar_let(term), { fuel: 200 });
+        if (shared.$ === "Normal" && desugared.$ === "Normal" && !equal(shared.term, desugared.term)) {
+          return `${show_de_bruijn(term)} normalizes to ${show_de_bruijn(shared.term)}, but desugared to ${show_de_bruijn(desugared.term)}`;
+        }
+      }
+      return null;
+    },
+  },
+  {
+    name: "a let value is reduced at most once",
+    run: () => {
+      // Each strategy takes as many beta steps as there are redexes it would reduce in one copy of the value
+      var cases: [string, Strategy, number][] = [
+        ["let x = (λy y) z in f x x", "normal_order", 1],
+        ["let x = (λy y) z in x x", "normal_order", 1],
+        ["let x = (λy y) z in f x x", "head", 0],
+        ["let x = (λy y) z in f x x", "call_by_name", 0],
+        ["let x = (λy y) z in x x", "call_by_name", 1],
+        ["let x = (λx x x) (λx x x) in f x", "call_by_name", 0],
+      ];
+      for (var [code, strategy, wanted] of cases) {
+        var found = beta_steps(code, strategy);
+        if (found !== wanted) {
+          return `${code} takes ${found} beta steps under ${strategy}, not ${wanted}`;
+        }
+      }
+      return null;
+    },
+  },
+];
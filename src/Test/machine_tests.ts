@@ -0,0 +1,36 @@
+import { Test } from "./_";
+import { call_by_need } from "../Machine/call_by_need";
+import { nbe } from "../Machine/nbe";
+import { with_term } from "./with_term";
+
+// Builds a Church numeral
+// - n: the number
+// = the source of λf x. f (f ... (f x))
+function numeral(n: number): string {
+  return `λf x. ${"f (".repeat(n)}x${")".repeat(n)}`;
+}
+
+// A term whose normal form, g applied a million times to y, is far deeper
+// than the default host stack, whatever the platform
+const DEEP = `(λm n f. m (n f)) (${numeral(1000)}) (${numeral(1000)}) g y`;
+
+export const machine_tests: Test[] = [
+  {
+    name: "call-by-need reports stack exhaustion apart from fuel",
+    run: () => {
+      return with_term(DEEP, "", parsed => {
+        var outcome = call_by_need(parsed.term, { fuel: 10000000 });
+        return outcome.$ === "StackOverflow" ? null : `got ${outcome.$}`;
+      });
+    },
+  },
+  {
+    name: "normalization by evaluation reports stack exhaustion apart from fuel",
+    run: () => {
+      return with_term(DEEP, "", parsed => {
+        var outcome = nbe(parsed.term, { fuel: 10000000 });
+        return outcome.$ === "StackOverflow" ? null : `got ${outcome.$}`;
+      });
+    },
+  },
+];
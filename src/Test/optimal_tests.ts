@@ -0,0 +1,46 @@
+import { Test } from "./_";
+import { OptimalComparison } from "../Net/_";
+import { compare_optimal } from "../Net/optimal";
+import { with_term } from "./with_term";
+
+// The definitions the terms below refer to
+const DEFS = "id = λx x; two = λf x. f (f x);";
+
+// Runs optimal reduction on a term, next to normal order
+// - source: the term's source
+// = the comparison, or a description of why the term could not be read
+function compare(source: string): OptimalComparison | string {
+  return with_term(source, DEFS, (parsed, program) => compare_optimal(parsed.term, { defs: program.defs, fuel: 1000000 }));
+}
+
+export const optimal_tests: Test[] = [
+  {
+    // The figures quoted in the README, also printed by npm run bench
+    name: "optimal reduction of two two two two id x",
+    run: () => {
+      var compared = compare("two two two two id x");
+      if (typeof compared === "string") {
+        return compared;
+      }
+      var { optimal, beta_steps, agree } = compared;
+      if (optimal.$ !== "Done" || agree !== true) {
+        return `got ${optimal.$}, agreeing: ${agree}`;
+      }
+      if (optimal.interactions.beta !== 27 || beta_steps !== 258294) {
+        return `got ${optimal.interactions.beta} beta interactions against ${beta_steps} beta steps`;
+      }
+      return null;
+    },
+  },
+  {
+    name: "optimal reduction does not claim a result for self-application",
+    run: () => {
+      var compared = compare("(λx. x x) two");
+      if (typeof compared === "string") {
+        return compared;
+      }
+      var { optimal, agree } = compared;
+      return optimal.$ === "Done" && agree !== false ? "self-application was read back as if supported" : null;
+    },
+  },
+];
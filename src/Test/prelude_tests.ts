@@ -0,0 +1,50 @@
+import { Test } from "./_";
+import { load_prelude, PRELUDE } from "../Program/prelude";
+import { parse, MAX_NUMERAL } from "../Parser/parse";
+import { normalize } from "../Reducer/normalize";
+import { equal } from "../Term/equal";
+import { church_numeral } from "../Term/church";
+import { with_term } from "./with_term";
+
+export const prelude_tests: Test[] = [
+  {
+    name: "the prelude loads",
+    run: () => {
+      for (var inline of [false, true]) {
+        var loaded = load_prelude({ inline });
+        if (loaded.$ === "Err") {
+          return loaded.error.message;
+        }
+      }
+      return null;
+    },
+  },
+  {
+    name: "prelude arithmetic",
+    run: () => {
+      for (var [code, wanted] of [["add 2 3", 5], ["mul 2 3", 6], ["pow 2 3", 8], ["pred 3", 2], ["sub 5 2", 3]] as [string, number][]) {
+        var found = with_term(code, PRELUDE, (parsed, program) => {
+          var reduced = normalize(parsed.term, { strategy: "normal_order", defs: program.defs });
+          return reduced.$ === "Normal" && equal(reduced.term, church_numeral(wanted)) ? null : `${code} is not ${wanted}`;
+        });
+        if (found !== null) {
+          return found;
+        }
+      }
+      return null;
+    },
+  },
+  {
+    name: "numerals are bounded",
+    run: () => {
+      if (parse(String(MAX_NUMERAL)).$ !== "Ok") {
+        return `${MAX_NUMERAL} does not parse`;
+      }
+      var parsed = parse(`λx. ${MAX_NUMERAL + 1}`);
+      if (parsed.$ === "Ok") {
+        return `${MAX_NUMERAL + 1} parses`;
+      }
+      return parsed.error.column === 5 ? null : `the error is at column ${parsed.error.column}`;
+    },
+  },
+];
@@ -0,0 +1,58 @@
+import { Term } from "../Term/_";
+
+// Generates pseudo-random terms, reproducibly from a seed
+// Variables are bound or free (past the enclosing binders), lambdas are
+// sometimes stacked deep enough for multi-digit indices, and the term may
+// contain local definitions and references
+// - seed: the seed of the generator
+// - options.size: the number of nodes to aim for
+// - options.refs: the names of the definitions references may point to
+// = a function that returns a new term at each call
+export function random_terms(seed: number, options: { size?: number, refs?: string[] } = {}): () => Term {
+  var next = mulberry32(seed);
+  var pick = (n: number): number => Math.floor(next() * n);
+  var refs = options.refs ?? [];
+
+  var term = (depth: number, size: number): Term => {
+    if (size <= 1) {
+      if (refs.length > 0 && pick(8) === 0) {
+        return { $: "Ref", name: refs[pick(refs.length)] };
+      }
+      // Free variables are numbered past the binders, as in parse
+      return { $: "Var", index: pick(depth + 3) };
+    }
+    switch (pick(4)) {
+      case 0: {
+        // A stack of binders, at times deep enough for indices of two digits
+        var binders = pick(6) === 0 ? 10 + pick(5) : 1;
+        var body = term(depth + binders, size - 1);
+        for (var i = 0; i < binders; i++) {
+          body = { $: "Lam", body };
+        }
+        return body;
+      }
+      case 1: {
+        var value = term(depth, Math.floor(size / 2));
+        return { $: "Let", value, body: term(depth + 1, size - 1 - Math.floor(size / 2)) };
+      }
+      default: {
+        var left = 1 + pick(size - 1);
+        return { $: "App", func: term(depth, left), arg: term(depth, size - left) };
+      }
+    }
+  };
+
+  return () => term(0, 1 + pick(options.size ?? 20));
+}
+
+// A small seeded pseudo-random number generator
+// - seed: the seed
+// = a function returning numbers uniformly drawn from [0, 1)
+function mulberry32(seed: number): () => number {
+  return () => {
+    seed = (seed + 0x6d2b79f5) | 0;
+    var t = Math.imul(seed ^ (seed >>> 15), 1 | seed);
+    t = (t + Math.imul(t ^ (t >>> 7), 61 | t)) ^ t;
+    return ((t ^ (t >>> 14)) >>> 0) / 4294967296;
+  };
+}
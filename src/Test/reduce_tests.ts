@@ -0,0 +1,81 @@
+import { Test } from "./_";
+import { parse } from "../Parser/parse";
+import { show_de_bruijn } from "../Term/show_de_bruijn";
+import { normalize } from "../Reducer/normalize";
+import { Strategy } from "../Reducer/_";
+import { load_prelude } from "../Program/prelude";
+
+// The outcome expected under each strategy: a normal form in de Bruijn
+// notation, "diverges" for a detected cycle, or "parse error"
+type Expected = string | { [name in Strategy]: string };
+
+// The regression cases that main used to print, and cases using the prelude,
+// with their expected outcomes
+const CASES: Array<[string, Expected]> = [
+  ["(λa a) λb b", "λ0"],
+  ["(λa λb b a)", "λλ0 1"],
+  ["((λa λb b a) λc c)", "λ0 (λ0)"],
+  ["λa (λb b) a", weak_and_strong("λ(λ0) 0", "λ0", "λ0")],
+  ["λa (λb λc b) a", weak_and_strong("λ(λλ1) 0", "λλ1", "λλ1")],
+  ["(λx x x) (λx x x)", "diverges"],
+  [
+    "(\\m n f x. m f (n f x)) (\\f x. f (f x)) (\\f x. f x)",
+    weak_and_strong("λλ(λλ1 (1 0)) 1 ((λλ1 0) 1 0)", "λλ1 (1 ((λλ1 0) 1 0))", "λλ1 (1 (1 0))"),
+  ],
+  ["f ((λx x) y)", weak_and_strong("f ((λ0) y)", "f ((λ0) y)", "f y")],
+  ["(λx x y", "parse error"],
+  // Definitions of the prelude unfold by the delta rule
+  [
+    "add 2 3",
+    weak_and_strong("λλ(λλ1 (1 0)) 1 ((λλ1 (1 (1 0))) 1 0)", "λλ1 (1 ((λλ1 (1 (1 0))) 1 0))", "λλ1 (1 (1 (1 (1 0))))"),
+  ],
+  ["not true", "λλ0"],
+  ["fst (pair a b)", "a"],
+];
+
+// Builds the outcomes of a term whose normal form depends on how far strategies reduce
+// - weak: the outcome of call by value and call by name
+// - head: the outcome of head reduction
+// - strong: the outcome of the strategies that reduce everywhere
+// = the outcome under each strategy
+function weak_and_strong(weak: string, head: string, strong: string): Expected {
+  return {
+    call_by_value: weak,
+    call_by_name: weak,
+    head,
+    normal_order: strong,
+    applicative_order: strong,
+    strong,
+  };
+}
+
+// Normalizes each case under every strategy, with the prelude in scope
+export const reduce_tests: Test[] = CASES.map(([input, expected]) => ({
+  name: `reduce ${input}`,
+  run: () => {
+    var prelude = load_prelude();
+    if (prelude.$ === "Err") {
+      return prelude.error.message;
+    }
+    var defs = prelude.program.defs;
+    var result = parse(input, { defs: new Set(defs.keys()) });
+    if (result.$ === "Err") {
+      return expected === "parse error" ? null : `parse error: ${result.error.message}`;
+    }
+    var failures: string[] = [];
+    for (var strategy of STRATEGIES) {
+      var outcome = normalize(result.term, { strategy, fuel: 1000, defs });
+      var found = outcome.$ === "Normal" ? show_de_bruijn(outcome.term, { free: result.free })
+        : outcome.$ === "Cycle" ? "diverges"
+        : outcome.$;
+      var wanted = typeof expected === "string" ? expected : expected[strategy];
+      if (found !== wanted) {
+        failures.push(`${strategy}: got ${found}, expected ${wanted}`);
+      }
+    }
+    return failures.length > 0 ? failures.join("; ") : null;
+  },
+}));
+
+// Every strategy, in the order they are reported
+const STRATEGIES: Strategy[] = ["call_by_value", "call_by_name", "normal_order", "applicative_order", "head", "strong"];
@@ -0,0 +1,54 @@
+import { Test } from "./_";
+import { new_session, run_line } from "../Repl/command";
+import { doubling } from "./doubling";
+
+// Runs lines of REPL input in a new session
+// - lines: the lines typed by the user
+// = the text printed for each line
+function run_lines(lines: string[]): string[] {
+  var session = new_session();
+  return lines.map(line => {
+    var reply = run_line(session, line);
+    return reply.$ === "Print" ? reply.text : "";
+  });
+}
+
+export const repl_tests: Test[] = [
+  {
+    name: "definitions are shown as written",
+    run: () => {
+      var printed = run_lines([":delta off", ...doubling(100, "λx x", d => `${d} ${d}`).split("\n")]);
+      return printed[100] === "d100 = d99 d99   ~   d99 d99" ? null : `d100 is shown as ${printed[100].slice(0, 100)}`;
+    },
+  },
+  {
+    name: "a definition others refer to cannot be redefined",
+    run: () => {
+      var printed = run_lines(["two = λf x. f (f x)", "four = two two", "two = λf x. f x", "two = λf x. f (f x)", "four"]);
+      if (printed[2] !== "Cannot redefine two: four refers to it") {
+        return `the redefinition prints ${printed[2]}`;
+      }
+      if (!printed[3].startsWith("two = ")) {
+        return `an identical definition prints ${printed[3]}`;
+      }
+      return printed[4].startsWith("λλ1 (1 (1 (1 0)))") ? null : `four is now ${printed[4]}`;
+    },
+  },
+  {
+    name: "only normal forms are read as data",
+    run: () => {
+      var printed = run_lines([":prelude", "add 1 2", ":step (λx x) 0", ":fuel 1", "(λx x) ((λx x) 0)"]);
+      if (!printed[1].startsWith("λλ1 (1 (1 0))") || !printed[1].includes("   =   3")) {
+        return `add 1 2 prints ${printed[1]}`;
+      }
+      var prelude = printed[0].split("\n").find(line => line.startsWith("false = "));
+      if (prelude?.includes("   =   ")) {
+        return `the prelude prints ${prelude}`;
+      }
+      if (printed[2].includes("   =   ")) {
+        return `a step prints ${printed[2]}`;
+      }
+      return printed[4].includes("   =   ") ? `a term out of fuel prints ${printed[4]}` : null;
+    },
+  },
+];
@@ -0,0 +1,59 @@
+import { Test } from "./_";
+import { Term } from "../Term/_";
+import { equal } from "../Term/equal";
+import { show_de_bruijn } from "../Term/show_de_bruijn";
+import { parse_de_bruijn } from "../Parser/parse_de_bruijn";
+import { random_terms } from "./random_term";
+
+// The definitions that generated terms refer to
+const REFS = ["id", "two"];
+
+// The names given to the first free variables; the others print as raw indices
+const FREE = ["x", "y"];
+
+// Checks that parse_de_bruijn reads back what show_de_bruijn prints
+// - term: the term to print and read back
+// = null if the term comes back unchanged, or the failure
+function round_trip(term: Term): string | null {
+  var shown = show_de_bruijn(term, { free: FREE });
+  var parsed = parse_de_bruijn(shown, { free: FREE, defs: new Set(REFS) });
+  if (parsed.$ === "Err") {
+    return `${shown} does not parse: ${parsed.error.message}`;
+  }
+  if (!equal(parsed.term, term)) {
+    return `${shown} reads back as ${show_de_bruijn(parsed.term, { free: FREE })}`;
+  }
+  return null;
+}
+
+// Terms written by hand for the cases generated terms may miss
+const CASES: string[] = [
+  "λλλλλλλλλλλλ11 (10 0)",
+  "((λ((0))) (λλ(1 (0))))",
+  "let λ0 in let 0 in 1 0",
+  "λx (y 5)",
+  "id (two 12)",
+];
+
+export const round_trip_tests: Test[] = [
+  ...CASES.map(source => ({
+    name: `round trip ${source}`,
+    run: () => {
+      var parsed = parse_de_bruijn(source, { free: FREE, defs: new Set(REFS) });
+      return parsed.$ === "Err" ? `does not parse: ${parsed.error.message}` : round_trip(parsed.term);
+    },
+  })),
+  {
+    name: "round trip of 2000 generated terms",
+    run: () => {
+      var generate = random_terms(1, { size: 40, refs: REFS });
+      for (var i = 0; i < 2000; i++) {
+        var failure = round_trip(generate());
+        if (failure) {
+          return failure;
+        }
+      }
+      return null;
+    },
+  },
+];
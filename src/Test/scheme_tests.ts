@@ -0,0 +1,62 @@
+import { Test } from "./_";
+import { load_source } from "../Program/load";
+import { infer, infer_scheme } from "../Type/infer";
+import { infer_defs } from "../Type/infer_defs";
+import { show_scheme } from "../Type/show_type";
+import { with_term } from "./with_term";
+
+// The definitions whose schemes are checked
+const DEFS = "id = λx x; compose = λf g x. f (g x); pair = λa b s. s a b; twice = λf x. f (f x); self = λx x x; both = pair (id 1) (id id);";
+
+// The scheme of each definition, or its error
+const SCHEMES: [string, string][] = [
+  ["id", "∀a. a -> a"],
+  ["compose", "∀a b c. (a -> b) -> (c -> a) -> c -> b"],
+  ["pair", "∀a b c. a -> b -> (a -> b -> c) -> c"],
+  ["twice", "∀a. (a -> a) -> a -> a"],
+  ["self", "error"],
+  // Each reference instantiates the scheme of id afresh
+  ["both", "∀a b c d. (((a -> b) -> a -> b) -> (c -> c) -> d) -> d"],
+];
+
+export const scheme_tests: Test[] = [
+  {
+    name: "let-bound variables are polymorphic",
+    run: () => {
+      return with_term("let i = λx x in i i", "", parsed => {
+        var scheme = infer_scheme(parsed.term);
+        if (scheme.$ === "Err" || show_scheme(scheme.scheme) !== "∀a. a -> a") {
+          return `got ${scheme.$ === "Ok" ? show_scheme(scheme.scheme) : scheme.error.message}`;
+        }
+        // Without generalization, i has one type, which cannot be applied to itself
+        return infer(parsed.term).$ === "Err" ? null : "monomorphic inference typed i i";
+      });
+    },
+  },
+  {
+    name: "lambda-bound variables are not generalized",
+    run: () => {
+      return with_term("λi. let j = i in j j", "", parsed => {
+        return infer_scheme(parsed.term).$ === "Err" ? null : "j was generalized over the type of i";
+      });
+    },
+  },
+  {
+    name: "schemes of definitions",
+    run: () => {
+      var loaded = load_source(DEFS);
+      if (loaded.$ === "Err") {
+        return loaded.error.message;
+      }
+      var schemes = infer_defs(loaded.program.defs);
+      for (var [name, wanted] of SCHEMES) {
+        var inferred = schemes.get(name)!;
+        var found = inferred.$ === "Ok" ? show_scheme(inferred.scheme) : "error";
+        if (found !== wanted) {
+          return `${name} : ${found}, not ${wanted}`;
+        }
+      }
+      return null;
+    },
+  },
+];
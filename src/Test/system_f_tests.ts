@@ -0,0 +1,66 @@
+import { Test } from "./_";
+import { equal } from "../Term/equal";
+import { normalize } from "../Reducer/normalize";
+import { parse_f } from "../SystemF/parse_f";
+import { check_f } from "../SystemF/check_f";
+import { erase_f } from "../SystemF/erase_f";
+import { show_ftype } from "../SystemF/show_ftype";
+import { with_term } from "./with_term";
+
+// Type checks a System F term
+// - code: the term's source
+// = its type, or the error and its path
+function check_source(code: string): string {
+  var parsed = parse_f(code);
+  if (parsed.$ === "Err") {
+    return `parse error: ${parsed.error.message}`;
+  }
+  var checked = check_f(parsed.term);
+  return checked.$ === "Ok" ? show_ftype(checked.type) : `error at [${checked.error.path.join(", ")}]: ${checked.error.message}`;
+}
+
+// Terms and their types
+const CASES: [string, string][] = [
+  ["Λa. λ(x : a) x", "∀a. a → a"],
+  ["Λa. λ(f : a → a) (x : a). f (f x)", "∀a. (a → a) → a → a"],
+  ["Λa b. λ(x : a) (y : b). x", "∀a. ∀b. a → b → a"],
+  ["Λb. (λ(x : b) x) [b]", "error at [body]: λ(x : b). x has type b → b, which is not polymorphic"],
+  ["Λa b. λ(x : a) (y : b). (λ(z : a) z) y", "error at [body, body, body, body]: λ(z : a). z expects an argument of type a, but y has type b"],
+  // Instantiating ∀a. ∀b. a → b → a at an outer b must not capture it
+  ["Λb. (Λa b. λ(x : a) (y : b). x) [b]", "∀b. ∀b'. b → b' → b"],
+  // Instantiating under a ∀ lowers the variables bound outside it
+  ["Λc d. (Λa. λ(x : d) x) [c]", "∀c. ∀d. d → d"],
+];
+
+// Squares the Church numeral two, at the type of its argument
+const SQUARE = "Λa. (λ(n : ∀b. (b → b) → b → b). n [a → a] (n [a])) (Λb. λ(f : b → b) (x : b). f (f x))";
+
+export const system_f_tests: Test[] = [
+  ...CASES.map(([code, wanted]) => ({
+    name: `check ${code}`,
+    run: () => {
+      var found = check_source(code);
+      return found === wanted ? null : `got ${found}`;
+    },
+  })),
+  {
+    name: "erased terms normalize like their untyped counterparts",
+    run: () => {
+      if (check_source(SQUARE) !== "∀a. (a → a) → a → a") {
+        return `the square of two has type ${check_source(SQUARE)}`;
+      }
+      var parsed = parse_f(SQUARE);
+      if (parsed.$ === "Err") {
+        return parsed.error.message;
+      }
+      var erased = normalize(erase_f(parsed.term));
+      return with_term("(λn. n n) (λf x. f (f x))", "", untyped => {
+        var expected = normalize(untyped.term);
+        if (erased.$ !== "Normal" || expected.$ !== "Normal") {
+          return "no normal form";
+        }
+        return equal(erased.term, expected.term) ? null : "the erased term has another normal form";
+      });
+    },
+  },
+];
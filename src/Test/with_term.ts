@@ -0,0 +1,24 @@
+import { ParseResult } from "../Parser/_";
+import { parse } from "../Parser/parse";
+import { Program } from "../Program/_";
+import { load_source } from "../Program/load";
+
+// Represents a successfully parsed term
+export type Parsed = Extract<ParseResult, { $: "Ok" }>;
+
+// Parses a term, with some definitions in scope, and runs a check on it
+// - code: the term's source
+// - source: the source of the definitions it may refer to, "" for none
+// - run: the check, given the parsed term and the loaded definitions
+// = the outcome of the check, or the message of the first parse error
+export function with_term<T>(code: string, source: string, run: (parsed: Parsed, program: Program) => T): T | string {
+  var loaded = load_source(source);
+  if (loaded.$ === "Err") {
+    return loaded.error.message;
+  }
+  var parsed = parse(code, { defs: new Set(loaded.program.defs.keys()) });
+  if (parsed.$ === "Err") {
+    return parsed.error.message;
+  }
+  return run(parsed, loaded.program);
+}
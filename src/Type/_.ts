@@ -0,0 +1,45 @@
+import { Path, Term } from "../Term/_";
+import { Annotations } from "../Parser/_";
+
+// Represents a simple type
+// - TVar: a type variable, by number
+// - Arrow: the type of functions from one type to another
+export type Type
+  = { $: "TVar", id: number }
+  | { $: "Arrow", from: Type, to: Type };
+
+// Represents a type scheme, i.e. a type polymorphic in some of its variables
+// - vars: the numbers of the quantified type variables
+// - type: the type, which can be instantiated with any types for those variables
+export type Scheme = { vars: number[], type: Type };
+
+// Represents a term as it was parsed, with the type annotations written in its source
+// - term: the term, whose paths key the annotations
+// - annotations: its annotations
+export type Annotated = { term: Term, annotations: Annotations };
+
+// Represents why a term has no type
+// - path: the path to the subterm where inference failed
+// - message: a description of the failure
+export type TypeError = { $: "TypeError", path: Path, message: string };
+
+// Represents the result of type inference
+// - Ok: the principal type of the term, and the types its free variables must have
+// - Err: why and where inference failed
+export type Inference
+  = { $: "Ok", type: Type, free: Type[] }
+  | { $: "Err", error: TypeError };
+
+// Represents the result of bidirectional type checking
+// - Ok: the type of the term
+// - Err: why and where checking failed
+export type Checking
+  = { $: "Ok", type: Type }
+  | { $: "Err", error: TypeError };
+
+// Represents the result of Hindley–Milner type inference
+// - Ok: the principal type scheme of the term, and the types its free variables must have
+// - Err: why and where inference failed
+export type SchemeInference
+  = { $: "Ok", scheme: Scheme, free: Type[] }
+  | { $: "Err", error: TypeError };
@@ -0,0 +1,267 @@
+import { Path, Term } from "../Term/_";
+import { Annotations } from "../Parser/_";
+import { path_key } from "../Term/path_key";
+import { Annotated, Checking, Type, TypeError } from "./_";
+import { show_type } from "./show_type";
+import { is_type_error, type_error } from "./error";
+
+// The state of one bidirectional type check
+// - annotations: the annotations of the term being checked, the whole term or a definition
+// - defs: the top-level definitions, with their own annotations
+// - names: the name of each type variable; variables of the same name are the
+//   same type, in the term and in the definitions it refers to
+// - synthesized: the type synthesized for each definition, or why there is none
+// - checked: the outcome of checking a definition against a type, by definition and type
+type Checker = {
+  annotations: Annotations,
+  defs: Map<string, Annotated>,
+  names: Map<number, string>,
+  synthesized: Map<string, Type | TypeError>,
+  checked: Map<string, TypeError | null>,
+};
+
+// Checks a term against its type annotations, bidirectionally
+// Types flow both ways: an application synthesizes the type of its function
+// and checks its argument against the domain, while an unannotated lambda can
+// only be checked against a known arrow type. So annotations are needed on
+// the binders of lambdas in function position, and at the root unless it is
+// an annotated lambda. Type variables stand for fixed unknown types, and no
+// unification happens: every error is local to the subterm it points at.
+// - term: the Term to check, as returned by parse
+// - annotations: the annotations returned with it
+// - defs: the definitions the term may refer to, as parsed with their annotations;
+//   each is checked once per type it is used at
+// = the type of the term, or a TypeError locating the offending subterm
+export function check(term: Term, annotations: Annotations, defs: Map<string, Annotated> = new Map()): Checking {
+  var checker: Checker = { annotations, defs, names: new Map(annotations.names), synthesized: new Map(), checked: new Map() };
+  try {
+    return { $: "Ok", type: synthesize(term, [], [], checker) };
+  } catch (e) {
+    if (is_type_error(e)) {
+      return { $: "Err", error: e };
+    }
+    throw e;
+  }
+}
+
+// Synthesizes the type of a subterm from the subterm itself
+// - term: the subterm
+// - ctx: the types of the enclosing binders, innermost last
+// - path: the path from the whole term to the subterm
+// - checker: the state of the check
+// = the type of the subterm
+function synthesize(term: Term, ctx: Type[], path: Path, checker: Checker): Type {
+  var ascribed = checker.annotations.terms.get(path_key(path));
+  if (!ascribed) {
+    return synthesize_bare(term, ctx, path, checker);
+  }
+  // Nested annotations, as in ((M : a) : b), must all agree
+  against_bare(term, ascribed[0], ctx, path, checker);
+  for (var type of ascribed.slice(1)) {
+    expect(ascribed[0], type, path, checker);
+  }
+  return ascribed[0];
+}
+
+// Synthesizes the type of a subterm, ignoring the annotations around it
+// - term: the subterm
+// - ctx: the types of the enclosing binders, innermost last
+// - path: the path from the whole term to the subterm
+// - checker: the state of the check
+// = the type of the subterm
+function synthesize_bare(term: Term, ctx: Type[], path: Path, checker: Checker): Type {
+  switch (term.$) {
+    case "Var": {
+      if (term.index >= ctx.length) {
+        throw type_error(path, "the type of a free variable is unknown");
+      }
+      return ctx[ctx.length - 1 - term.index];
+    }
+    case "Lam": {
+      var from = checker.annotations.binders.get(path_key(path));
+      if (!from) {
+        throw type_error(path, "cannot synthesize the type of an unannotated lambda; write λ(x : T) M or (M : T)");
+      }
+      ctx.push(from);
+      var to = synthesize(term.body, ctx, [...path, "body"], checker);
+      ctx.pop();
+      return { $: "Arrow", from, to };
+    }
+    case "App": {
+      var func = synthesize(term.func, ctx, [...path, "func"], checker);
+      if (func.$ !== "Arrow") {
+        throw type_error([...path, "func"], `this has type ${show(func, checker)}, which is not a function type`);
+      }
+      against(term.arg, func.from, ctx, [...path, "arg"], checker);
+      return func.to;
+    }
+    case "Let": {
+      ctx.push(synthesize(term.value, ctx, [...path, "value"], checker));
+      var body = synthesize(term.body, ctx, [...path, "body"], checker);
+      ctx.pop();
+      return body;
+    }
+    case "Ref": {
+      var synthesized = synthesize_def(term.name, path, checker);
+      if (!is_type_error(synthesized)) {
+        return synthesized;
+      }
+      throw type_error(path, `cannot synthesize the type of the definition ${term.name} (${synthesized.message}); write (${term.name} : T)`);
+    }
+  }
+}
+
+// Checks a subterm against the type it is expected to have
+// - term: the subterm
+// - type: the expected type
+// - ctx: the types of the enclosing binders, innermost last
+// - path: the path from the whole term to the subterm
+// - checker: the state of the check
+function against(term: Term, type: Type, ctx: Type[], path: Path, checker: Checker): void {
+  if (checker.annotations.terms.has(path_key(path))) {
+    expect(synthesize(term, ctx, path, checker), type, path, checker);
+  } else {
+    against_bare(term, type, ctx, path, checker);
+  }
+}
+
+// Checks a subterm against a type, ignoring the annotations around it
+// - term: the subterm
+// - type: the expected type
+// - ctx: the types of the enclosing binders, innermost last
+// - path: the path from the whole term to the subterm
+// - checker: the state of the check
+function against_bare(term: Term, type: Type, ctx: Type[], path: Path, checker: Checker): void {
+  switch (term.$) {
+    case "Lam": {
+      if (type.$ !== "Arrow") {
+        throw type_error(path, `a lambda cannot have type ${show(type, checker)}, which is not a function type`);
+      }
+      var from = checker.annotations.binders.get(path_key(path));
+      if (from) {
+        expect(from, type.from, path, checker);
+      }
+      ctx.push(type.from);
+      against(term.body, type.to, ctx, [...path, "body"], checker);
+      ctx.pop();
+      return;
+    }
+    case "Let": {
+      ctx.push(synthesize(term.value, ctx, [...path, "value"], checker));
+      against(term.body, type, ctx, [...path, "body"], checker);
+      ctx.pop();
+      return;
+    }
+    case "Ref": {
+      // A definition whose type can be synthesized must have the expected one
+      var synthesized = synthesize_def(term.name, path, checker);
+      if (!is_type_error(synthesized)) {
+        expect(synthesized, type, path, checker);
+        return;
+      }
+      // Otherwise it is checked on its own, so its errors are reported at the reference
+      var key = `${term.name} : ${JSON.stringify(type)}`;
+      var checked = checker.checked.get(key);
+      if (checked === undefined) {
+        checked = in_def(term.name, path, checker, (def, within) => against(def, type, [], [], within));
+        checker.checked.set(key, checked);
+      }
+      if (checked) {
+        throw type_error(path, `${term.name} does not have type ${show(type, checker)}: ${checked.message}`);
+      }
+      return;
+    }
+    default: {
+      expect(synthesize_bare(term, ctx, path, checker), type, path, checker);
+      return;
+    }
+  }
+}
+
+// Synthesizes the type of a definition from its annotations, once
+// - name: the name of the definition
+// - path: the path to the reference being checked
+// - checker: the state of the check
+// = the type of the definition, or why it cannot be synthesized
+function synthesize_def(name: string, path: Path, checker: Checker): Type | TypeError {
+  var synthesized = checker.synthesized.get(name);
+  if (synthesized === undefined) {
+    var type: Type | null = null;
+    var failure = in_def(name, path, checker, (def, within) => { type = synthesize(def, [], [], within); });
+    synthesized = failure ?? type!;
+    checker.synthesized.set(name, synthesized);
+  }
+  return synthesized;
+}
+
+// Runs a check on a definition, with the definition's own annotations
+// Its type variables are renamed into the checker's, matching them by name
+// - name: the name of the definition
+// - path: the path to the reference being checked
+// - checker: the state of the check
+// - run: the check to run on the definition's term
+// = null on success, or the error found in the definition
+function in_def(name: string, path: Path, checker: Checker, run: (def: Term, within: Checker) => void): TypeError | null {
+  var def = checker.defs.get(name);
+  if (!def) {
+    throw type_error(path, `${name} is not defined`);
+  }
+  var ids = new Map<number, Type>();
+  for (var [id, type_name] of def.annotations.names) {
+    var found = [...checker.names].find(([, other]) => other === type_name);
+    var global = found ? found[0] : checker.names.size;
+    checker.names.set(global, type_name);
+    ids.set(id, { $: "TVar", id: global });
+  }
+  var rename = (type: Type): Type => type.$ === "TVar"
+    ? ids.get(type.id) ?? type
+    : { $: "Arrow", from: rename(type.from), to: rename(type.to) };
+  var annotations: Annotations = {
+    binders: new Map([...def.annotations.binders].map(([key, type]) => [key, rename(type)])),
+    terms: new Map([...def.annotations.terms].map(([key, types]) => [key, types.map(rename)])),
+    names: checker.names,
+  };
+  try {
+    run(def.term, { ...checker, annotations });
+    return null;
+  } catch (e) {
+    if (is_type_error(e)) {
+      return e;
+    }
+    throw e;
+  }
+}
+
+// Ensures that a subterm's type is the one expected
+// - found: the type of the subterm
+// - wanted: the type it is expected to have
+// - path: the path from the whole term to the subterm
+// - checker: the state of the check
+function expect(found: Type, wanted: Type, path: Path, checker: Checker): void {
+  if (!type_equal(found, wanted)) {
+    throw type_error(path, `this has type ${show(found, checker)}, but ${show(wanted, checker)} was expected`);
+  }
+}
+
+// Checks if two types are the same
+// - a: a type
+// - b: another type
+// = true if both types are equal
+function type_equal(a: Type, b: Type): boolean {
+  switch (a.$) {
+    case "TVar": {
+      return b.$ === "TVar" && a.id === b.id;
+    }
+    case "Arrow": {
+      return b.$ === "Arrow" && type_equal(a.from, b.from) && type_equal(a.to, b.to);
+    }
+  }
+}
+
+// Converts a type to a string, with the type variable names of the source
+// - type: the type to show
+// - checker: the state of the check
+// = the string representation of the type
+function show(type: Type, checker: Checker): string {
+  return show_type(type, new Map(checker.names));
+}
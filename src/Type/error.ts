@@ -0,0 +1,17 @@
+import { Path } from "../Term/_";
+import { TypeError } from "./_";
+
+// Builds a type error, locating the offending subterm
+// - path: the path from the whole term to the subterm
+// - message: a description of the failure
+// = the TypeError
+export function type_error(path: Path, message: string): TypeError {
+  return { $: "TypeError", path, message };
+}
+
+// Checks if a thrown value is a type error
+// - value: the thrown value
+// = true if the value is a TypeError
+export function is_type_error(value: unknown): value is TypeError {
+  return typeof value === "object" && value !== null && (value as TypeError).$ === "TypeError";
+}
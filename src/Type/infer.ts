@@ -0,0 +1,220 @@
+import { Path, Term } from "../Term/_";
+import { Inference, Scheme, SchemeInference, Type } from "./_";
+import { resolve, Subst, unify } from "./unify";
+import { show_type } from "./show_type";
+import { is_type_error, type_error } from "./error";
+
+// The state of one type inference
+// - subst: the solution of the equations met so far
+// - next: the number of the next fresh type variable
+// - free: the type of each free variable, by its index at the top level
+// - defs: the top-level definitions
+// - def_schemes: the principal type scheme of each definition inferred so far
+// - polymorphic: whether local definitions are generalized
+type Inferrer = {
+  subst: Subst,
+  next: number,
+  free: Map<number, Type>,
+  defs: Map<string, Term>,
+  def_schemes: Map<string, Scheme>,
+  polymorphic: boolean,
+};
+
+// Infers the principal type of a term in the simply typed lambda calculus
+// Every subterm is given a type variable, and unification solves the equations
+// between them. A term is untypable when some type would have to contain
+// itself, as in λx x x. Definitions are typed once, and each reference gets
+// a fresh copy of their type, as if the definition were inlined. Local
+// definitions are typed as the redex (λx N) M, so their variable is monomorphic.
+// - term: the Term to type
+// - defs: the definitions the term may refer to
+// = the principal type of the term, or a TypeError locating the failure
+export function infer(term: Term, defs: Map<string, Term> = new Map()): Inference {
+  var inferred = run(term, defs, new Map(), false);
+  if (inferred.$ === "Err") {
+    return inferred;
+  }
+  return { $: "Ok", type: inferred.scheme.type, free: inferred.free };
+}
+
+// Infers the principal type scheme of a term, with Hindley–Milner let-polymorphism
+// Like infer, but the type of a local definition is generalized over the type
+// variables that its context does not constrain, so that let id = λx x in id id
+// is typable: each occurrence of the variable instantiates the scheme afresh.
+// - term: the Term to type
+// - defs: the definitions the term may refer to
+// - def_schemes: the schemes of the definitions already inferred, extended with new ones
+// = the principal type scheme of the term, or a TypeError locating the failure
+export function infer_scheme(term: Term, defs: Map<string, Term> = new Map(), def_schemes: Map<string, Scheme> = new Map()): SchemeInference {
+  return run(term, defs, def_schemes, true);
+}
+
+// Infers the type scheme of a term, then generalizes it
+// - term: the Term to type
+// - defs: the definitions the term may refer to
+// - def_schemes: the schemes of the definitions already inferred, extended with new ones
+// - polymorphic: whether local definitions are generalized
+// = the principal type scheme of the term, or a TypeError locating the failure
+function run(term: Term, defs: Map<string, Term>, def_schemes: Map<string, Scheme>, polymorphic: boolean): SchemeInference {
+  var inferrer: Inferrer = { subst: new Map(), next: 0, free: new Map(), defs, def_schemes, polymorphic };
+  try {
+    var type = infer_at(term, [], [], inferrer);
+    var free: Type[] = [];
+    for (var [index, free_type] of inferrer.free) {
+      free[index] = resolve(free_type, inferrer.subst);
+    }
+    return { $: "Ok", scheme: generalize(type, [], inferrer), free };
+  } catch (e) {
+    if (is_type_error(e)) {
+      return { $: "Err", error: e };
+    }
+    throw e;
+  }
+}
+
+// Infers the type of a subterm
+// - term: the subterm
+// - ctx: the type schemes of the enclosing binders, innermost last
+// - path: the path from the whole term to the subterm
+// - inferrer: the state of the inference
+// = the type of the subterm, up to the substitution
+function infer_at(term: Term, ctx: Scheme[], path: Path, inferrer: Inferrer): Type {
+  switch (term.$) {
+    case "Var": {
+      if (term.index < ctx.length) {
+        return instantiate(ctx[ctx.length - 1 - term.index], inferrer);
+      }
+      var index = term.index - ctx.length;
+      var type = inferrer.free.get(index);
+      if (!type) {
+        type = fresh(inferrer);
+        inferrer.free.set(index, type);
+      }
+      return type;
+    }
+    case "Lam": {
+      var from = fresh(inferrer);
+      ctx.push({ vars: [], type: from });
+      var to = infer_at(term.body, ctx, [...path, "body"], inferrer);
+      ctx.pop();
+      return { $: "Arrow", from, to };
+    }
+    case "App": {
+      var func = infer_at(term.func, ctx, [...path, "func"], inferrer);
+      var arg = infer_at(term.arg, ctx, [...path, "arg"], inferrer);
+      var result = fresh(inferrer);
+      var failure = unify(func, { $: "Arrow", from: arg, to: result }, inferrer.subst);
+      if (failure) {
+        var names = new Map<number, string>();
+        var func_type = show_type(resolve(func, inferrer.subst), names);
+        var arg_type = show_type(resolve(arg, inferrer.subst), names);
+        throw type_error(path, `cannot apply a function of type ${func_type} to an argument of type ${arg_type}: ${failure}`);
+      }
+      return result;
+    }
+    case "Let": {
+      var value = infer_at(term.value, ctx, [...path, "value"], inferrer);
+      ctx.push(inferrer.polymorphic ? generalize(value, ctx, inferrer) : { vars: [], type: value });
+      var body = infer_at(term.body, ctx, [...path, "body"], inferrer);
+      ctx.pop();
+      return body;
+    }
+    case "Ref": {
+      var scheme = inferrer.def_schemes.get(term.name);
+      if (!scheme) {
+        var def = inferrer.defs.get(term.name);
+        if (!def) {
+          return fresh(inferrer);
+        }
+        scheme = infer_def(term.name, def, path, inferrer);
+        inferrer.def_schemes.set(term.name, scheme);
+      }
+      return instantiate(scheme, inferrer);
+    }
+  }
+}
+
+// Infers the principal type scheme of a definition, on its own
+// - name: the name of the definition
+// - def: the closed term it is defined as
+// - path: the path to the reference being typed
+// - inferrer: the state of the inference, whose definition schemes are shared
+// = the principal type scheme of the definition
+function infer_def(name: string, def: Term, path: Path, inferrer: Inferrer): Scheme {
+  var inferred = run(def, inferrer.defs, inferrer.def_schemes, inferrer.polymorphic);
+  if (inferred.$ === "Err") {
+    throw type_error(path, `the definition of ${name} is not typable: ${inferred.error.message}`);
+  }
+  return inferred.scheme;
+}
+
+// Creates a type variable that was not used before
+// - inferrer: the state of the inference
+// = the new type variable
+function fresh(inferrer: Inferrer): Type {
+  return { $: "TVar", id: inferrer.next++ };
+}
+
+// Quantifies a type over the variables that neither its context nor the free variables constrain
+// - type: the type to generalize
+// - ctx: the type schemes of the enclosing binders
+// - inferrer: the state of the inference
+// = the type scheme
+function generalize(type: Type, ctx: Scheme[], inferrer: Inferrer): Scheme {
+  type = resolve(type, inferrer.subst);
+  var fixed = new Set<number>();
+  for (var scheme of ctx) {
+    var bound = new Set(scheme.vars);
+    type_vars(resolve(scheme.type, inferrer.subst)).forEach(id => bound.has(id) || fixed.add(id));
+  }
+  for (var free_type of inferrer.free.values()) {
+    type_vars(resolve(free_type, inferrer.subst)).forEach(id => fixed.add(id));
+  }
+  return { vars: type_vars(type).filter(id => !fixed.has(id)), type };
+}
+
+// Copies a type scheme's type with fresh variables for the quantified ones
+// - scheme: the scheme to instantiate
+// - inferrer: the state of the inference
+// = the type
+function instantiate(scheme: Scheme, inferrer: Inferrer): Type {
+  if (scheme.vars.length === 0) {
+    return scheme.type;
+  }
+  var renamed = new Map<number, Type>(scheme.vars.map(id => [id, fresh(inferrer)]));
+  return rename(scheme.type, renamed);
+}
+
+// Replaces some type variables of a resolved type
+// - type: the type to copy
+// - renamed: the type replacing each variable to replace
+// = the copy
+function rename(type: Type, renamed: Map<number, Type>): Type {
+  switch (type.$) {
+    case "TVar": {
+      return renamed.get(type.id) ?? type;
+    }
+    case "Arrow": {
+      return { $: "Arrow", from: rename(type.from, renamed), to: rename(type.to, renamed) };
+    }
+  }
+}
+
+// Lists the type variables of a resolved type
+// - type: the type to look in
+// - vars: the variables found so far, extended in place
+// = the variables, in order of appearance
+function type_vars(type: Type, vars: number[] = []): number[] {
+  switch (type.$) {
+    case "TVar": {
+      if (!vars.includes(type.id)) {
+        vars.push(type.id);
+      }
+      return vars;
+    }
+    case "Arrow": {
+      type_vars(type.from, vars);
+      return type_vars(type.to, vars);
+    }
+  }
+}
@@ -0,0 +1,19 @@
+import { Term } from "../Term/_";
+import { Scheme, SchemeInference } from "./_";
+import { infer_scheme } from "./infer";
+
+// Infers the principal type scheme of every top-level definition
+// - defs: the definitions, which may refer to each other
+// = the scheme of each definition, or why it is not typable, in the order of the definitions
+export function infer_defs(defs: Map<string, Term>): Map<string, SchemeInference> {
+  var schemes = new Map<string, Scheme>();
+  var results = new Map<string, SchemeInference>();
+  for (var [name, def] of defs) {
+    var inferred = infer_scheme(def, defs, schemes);
+    if (inferred.$ === "Ok") {
+      schemes.set(name, inferred.scheme);
+    }
+    results.set(name, inferred);
+  }
+  return results;
+}
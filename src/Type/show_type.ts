@@ -0,0 +1,45 @@
+import { Scheme, Type } from "./_";
+
+// Converts a type to a string, naming type variables a, b, c... in order of appearance
+// Arrows associate to the right, e.g. (a -> b) -> a -> b
+// - type: the type to show
+// - names: the names given to type variables so far, extended with new ones;
+//   sharing it between calls names the variables of several types consistently
+// = the string representation of the type
+export function show_type(type: Type, names: Map<number, string> = new Map()): string {
+  switch (type.$) {
+    case "TVar": {
+      var name = names.get(type.id);
+      if (name === undefined) {
+        name = type_name(names.size);
+        names.set(type.id, name);
+      }
+      return name;
+    }
+    case "Arrow": {
+      var from = show_type(type.from, names);
+      var to = show_type(type.to, names);
+      return type.from.$ === "Arrow" ? `(${from}) -> ${to}` : `${from} -> ${to}`;
+    }
+  }
+}
+
+// Converts a type scheme to a string, e.g. ∀a b. (a -> b) -> a -> b
+// - scheme: the scheme to show
+// - names: the names given to type variables so far, extended with new ones
+// = the string representation of the scheme
+export function show_scheme(scheme: Scheme, names: Map<number, string> = new Map()): string {
+  var type = show_type(scheme.type, names);
+  if (scheme.vars.length === 0) {
+    return type;
+  }
+  return `∀${scheme.vars.map(id => names.get(id)).join(" ")}. ${type}`;
+}
+
+// Names the nth type variable
+// - n: the number of names given before
+// = a, b, ..., z, a1, b1, ...
+function type_name(n: number): string {
+  var letter = String.fromCharCode("a".charCodeAt(0) + n % 26);
+  return n < 26 ? letter : `${letter}${Math.floor(n / 26)}`;
+}
@@ -0,0 +1,70 @@
+import { Type } from "./_";
+import { show_type } from "./show_type";
+
+// Represents a substitution of types for type variables, by number
+export type Subst = Map<number, Type>;
+
+// Applies a substitution to a type, until no substituted variable is left
+// - type: the type to resolve
+// - subst: the substitution
+// = the type with every bound variable replaced
+export function resolve(type: Type, subst: Subst): Type {
+  switch (type.$) {
+    case "TVar": {
+      var bound = subst.get(type.id);
+      return bound ? resolve(bound, subst) : type;
+    }
+    case "Arrow": {
+      return { $: "Arrow", from: resolve(type.from, subst), to: resolve(type.to, subst) };
+    }
+  }
+}
+
+// Extends a substitution so that two types become equal
+// - a: a type
+// - b: the type it must equal
+// - subst: the substitution, updated in place
+// = null on success, or the reason why the types cannot be made equal
+export function unify(a: Type, b: Type, subst: Subst): string | null {
+  a = resolve(a, subst);
+  b = resolve(b, subst);
+  if (a.$ === "TVar" && b.$ === "TVar" && a.id === b.id) {
+    return null;
+  }
+  if (a.$ === "TVar") {
+    return bind(a.id, b, subst);
+  }
+  if (b.$ === "TVar") {
+    return bind(b.id, a, subst);
+  }
+  return unify(a.from, b.from, subst) ?? unify(a.to, b.to, subst);
+}
+
+// Binds a type variable to a type, unless the variable occurs in it
+// - id: the number of the type variable
+// - type: the resolved type to bind it to
+// - subst: the substitution, updated in place
+// = null on success, or the reason why the binding would be infinite
+function bind(id: number, type: Type, subst: Subst): string | null {
+  if (occurs(id, type)) {
+    var names = new Map<number, string>();
+    return `infinite type ${show_type({ $: "TVar", id }, names)} = ${show_type(type, names)}`;
+  }
+  subst.set(id, type);
+  return null;
+}
+
+// Checks if a type variable occurs in a resolved type
+// - id: the number of the type variable
+// - type: the type to look in
+// = true if the variable occurs in the type
+function occurs(id: number, type: Type): boolean {
+  switch (type.$) {
+    case "TVar": {
+      return type.id === id;
+    }
+    case "Arrow": {
+      return occurs(id, type.from) || occurs(id, type.to);
+    }
+  }
+}
@@ -0,0 +1,92 @@
+#!/usr/bin/env node
+import { Term } from "./Term/_";
+import { parse } from "./Parser/parse";
+import { load_source } from "./Program/load";
+import { benchmark } from "./Machine/benchmark";
+import { compare_optimal } from "./Net/optimal";
+
+// The definitions the workloads are built from
+const PRELUDE = `
+  id   = λx x;
+  succ = λn f x. f (n f x);
+  add  = λm n f x. m f (n f x);
+  mul  = λm n f. m (n f);
+  two  = λf x. f (f x);
+`;
+
+// Builds the source of a Church numeral
+// - n: the number
+// = the numeral, written as repeated successors of zero
+function numeral(n: number): string {
+  return `(${"succ (".repeat(n)}λf x. x${")".repeat(n)})`;
+}
+
+// The workloads, by name: each gives the source of a term of growing size
+// Applying a numeral to the identity runs through all of its successors,
+// so that the number of steps grows with n
+const WORKLOADS: { [name: string]: (n: number) => string } = {
+  "n id id":         n => `${numeral(n)} id id`,
+  "(n * n) id id":   n => `mul ${numeral(n)} ${numeral(n)} id id`,
+  "(n + n) succ id": n => `add ${numeral(n)} ${numeral(n)} succ id`,
+};
+
+// Builds a tower of exponentials, applied so that its normal form is small
+// - n: the height of the tower
+// = the source of two two ... two id x, which takes 2^2^...^2 steps to normalize
+function tower(n: number): string {
+  return `${"two ".repeat(n)}id x`;
+}
+
+// Times the abstract machines against the substitution reducer on every
+// workload, then compares optimal reduction with normal order on towers
+function main() {
+  var loaded = load_source(PRELUDE);
+  if (loaded.$ === "Err") {
+    throw new Error(loaded.error.message);
+  }
+  var defs = loaded.program.defs;
+  console.log("workload           n     machine   steps    machine ms   reducer ms   agree");
+  for (var name in WORKLOADS) {
+    for (var n of [10, 20, 40, 80]) {
+      var result = parse(WORKLOADS[name](n), { defs: new Set(defs.keys()), closed: true });
+      if (result.$ === "Err") {
+        throw new Error(result.error.message);
+      }
+      var term: Term = result.term;
+      var timings = benchmark(term, { defs });
+      for (var machine of ["krivine", "cek"] as const) {
+        var timing = timings[machine];
+        console.log([
+          name.padEnd(18),
+          String(n).padStart(3),
+          machine.padStart(11),
+          String(timing.steps).padStart(7),
+          timing.machine.toFixed(2).padStart(13),
+          timing.reducer.toFixed(2).padStart(12),
+          String(timing.agree).padStart(7),
+        ].join(""));
+      }
+    }
+  }
+
+  console.log();
+  console.log("workload                 beta steps   interactions   beta interactions   agree");
+  for (var height = 1; height <= 4; ++height) {
+    var source = tower(height);
+    var parsed = parse(source, { defs: new Set(defs.keys()) });
+    if (parsed.$ === "Err") {
+      throw new Error(parsed.error.message);
+    }
+    var { optimal, beta_steps, agree } = compare_optimal(parsed.term, { defs, fuel: 1000000 });
+    console.log([
+      source.padEnd(23),
+      String(beta_steps ?? "-").padStart(12),
+      String(optimal.interactions.total).padStart(15),
+      String(optimal.interactions.beta).padStart(20),
+      String(agree ?? "-").padStart(8),
+    ].join(""));
+  }
+}
+
+// Run the main function
+main();
@@ -0,0 +1,58 @@
+#!/usr/bin/env node
+import { Test } from "./Test/_";
+import { reduce_tests } from "./Test/reduce_tests";
+import { round_trip_tests } from "./Test/round_trip_tests";
+import { inline_tests } from "./Test/inline_tests";
+import { let_tests } from "./Test/let_tests";
+import { machine_tests } from "./Test/machine_tests";
+import { optimal_tests } from "./Test/optimal_tests";
+import { infer_tests } from "./Test/infer_tests";
+import { scheme_tests } from "./Test/scheme_tests";
+import { comb_tests } from "./Test/comb_tests";
+import { system_f_tests } from "./Test/system_f_tests";
+import { core_tests } from "./Test/core_tests";
+import { check_tests } from "./Test/check_tests";
+import { prelude_tests } from "./Test/prelude_tests";
+import { decode_tests } from "./Test/decode_tests";
+import { repl_tests } from "./Test/repl_tests";
+
+// Every test, by suite
+const TESTS: Test[] = [
+  ...reduce_tests,
+  ...round_trip_tests,
+  ...inline_tests,
+  ...let_tests,
+  ...machine_tests,
+  ...optimal_tests,
+  ...infer_tests,
+  ...scheme_tests,
+  ...comb_tests,
+  ...system_f_tests,
+  ...core_tests,
+  ...check_tests,
+  ...prelude_tests,
+  ...decode_tests,
+  ...repl_tests,
+];
+
+// Runs every test, reporting the failures, and fails the process if any test fails
+function main() {
+  var failed = 0;
+  for (var test of TESTS) {
+    var failure: string | null;
+    try {
+      failure = test.run();
+    } catch (e) {
+      failure = `threw ${e instanceof Error ? e.message : String(e)}`;
+    }
+    if (failure !== null) {
+      failed++;
+      console.log(`FAIL ${test.name}\n  ${failure}`);
+    }
+  }
+  console.log(`${TESTS.length - failed} passed, ${failed} failed`);
+  process.exitCode = failed > 0 ? 1 : 0;
+}
+
+// Run the main function
+main();